@@ -8,6 +8,22 @@ export interface BrawlerProfile {
     mission_joined_count: number;
 }
 
+export interface LeaderboardEntry {
+    rank: number;
+    id: number;
+    username: string;
+    display_name: string;
+    avatar_url: string;
+    total_points: number;
+    points: number;
+}
+
+export interface LeaderboardMe {
+    me: LeaderboardEntry;
+    above: LeaderboardEntry[];
+    below: LeaderboardEntry[];
+}
+
 export interface Passport {
     token_type: string;
     access_token: string;
@@ -3,4 +3,9 @@ export type MissionStatus = 'Open' | 'InProgress' | 'Completed' | 'Failed';
 export interface MissionFilter {
   name?: string;
   status?: MissionStatus;
+  // Comma separated, e.g. 'Open,InProgress'
+  statuses?: string;
+  chief_id?: number;
+  // Missions the signed-in brawler has joined as crew
+  member?: boolean;
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+export interface Page<T> {
+  items: T[];
+  total: number;
+  page: number;
+  limit: number;
+  next_offset_token: string | null;
+}
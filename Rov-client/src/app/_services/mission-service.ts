@@ -1,11 +1,12 @@
 import { Injectable, inject } from '@angular/core';
-import { HttpClient } from '@angular/common/http';
+import { HttpClient, HttpParams } from '@angular/common/http';
 import { firstValueFrom, lastValueFrom } from 'rxjs';
 import { environment } from '../../environments/environment';
 import { MissionFilter } from '../_models/mission-filter';
 import { Mission } from '../_models/mission';
 import { AddMission } from '../_models/add-mission';
-import { BrawlerProfile } from '../_models/brawler';
+import { BrawlerProfile, LeaderboardMe } from '../_models/brawler';
+import { Page } from '../_models/page';
 
 @Injectable({
   providedIn: 'root'
@@ -14,19 +15,21 @@ export class MissionService {
   private _api_url = environment.baseUrl + 'api/v1';
   private _http = inject(HttpClient);
 
-  async getLeaderboard(): Promise<BrawlerProfile[]> {
-    return lastValueFrom(this._http.get<BrawlerProfile[]>(`${this._api_url}/brawlers/leaderboard`));
+  async getLeaderboard(offsetToken: string | null = null): Promise<Page<BrawlerProfile>> {
+    return this.getPage<BrawlerProfile>(`${this._api_url}/brawlers/leaderboard`, offsetToken);
+  }
+
+  async getMyRank(): Promise<LeaderboardMe> {
+    return lastValueFrom(this._http.get<LeaderboardMe>(`${this._api_url}/brawlers/leaderboard/me`));
   }
 
   async getMe(): Promise<BrawlerProfile> {
     return lastValueFrom(this._http.get<BrawlerProfile>(`${this._api_url}/brawlers/me`));
   }
 
-  async getMyMissions(): Promise<Mission[]> {
+  async getMyMissions(offsetToken: string | null = null): Promise<Page<Mission>> {
     const url = this._api_url + '/view/my-missions';
-    const observable = this._http.get<Mission[]>(url);
-    const missions = await firstValueFrom(observable);
-    return missions;
+    return this.getPage<Mission>(url, offsetToken);
   }
 
   async add(mission: AddMission): Promise<number> {
@@ -36,12 +39,10 @@ export class MissionService {
     return response.mission_id;
   }
 
-  async gets(filter: MissionFilter): Promise<Mission[]> {
+  async gets(filter: MissionFilter, offsetToken: string | null = null): Promise<Page<Mission>> {
     const queryString = this.toQueryString(filter);
     const url = this._api_url + '/view/gets?' + queryString;
-    const observable = this._http.get<Mission[]>(url);
-    const missions = await firstValueFrom(observable);
-    return missions;
+    return this.getPage<Mission>(url, offsetToken);
   }
 
   async join(missionId: number): Promise<any> {
@@ -76,9 +77,9 @@ export class MissionService {
     return await firstValueFrom(this._http.get<BrawlerProfile[]>(url));
   }
 
-  async getChatMessages(missionId: number): Promise<any[]> {
+  async getChatMessages(missionId: number, offsetToken: string | null = null): Promise<Page<any>> {
     const url = `${this._api_url}/chat/${missionId}/messages`;
-    return await firstValueFrom(this._http.get<any[]>(url));
+    return this.getPage<any>(url, offsetToken);
   }
 
   async sendChatMessage(missionId: number, content: string): Promise<any> {
@@ -102,6 +103,15 @@ export class MissionService {
     return await firstValueFrom(this._http.patch(url, {}, { responseType: 'text' }));
   }
 
+  // List endpoints return one page at a time; pass the previous page's next_offset_token to get the next one
+  private async getPage<T>(url: string, offsetToken: string | null): Promise<Page<T>> {
+    let params = new HttpParams();
+    if (offsetToken) {
+      params = params.set('offset_token', offsetToken);
+    }
+    return await firstValueFrom(this._http.get<Page<T>>(url, { params }));
+  }
+
   private toQueryString(filter: MissionFilter): string {
     const params: string[] = [];
     if (filter.name && filter.name.trim()) {
@@ -110,6 +120,15 @@ export class MissionService {
     if (filter.status) {
       params.push(`status=${encodeURIComponent(filter.status)}`);
     }
+    if (filter.statuses) {
+      params.push(`statuses=${encodeURIComponent(filter.statuses)}`);
+    }
+    if (filter.chief_id) {
+      params.push(`chief_id=${filter.chief_id}`);
+    }
+    if (filter.member) {
+      params.push('member=true');
+    }
     return params.join('&');
   }
 }
\ No newline at end of file
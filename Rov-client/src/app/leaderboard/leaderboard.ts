@@ -13,6 +13,8 @@ export class LeaderboardComponent implements OnInit {
   private _missionService = inject(MissionService);
   private cdr = inject(ChangeDetectorRef);
   topBrawlers: any[] = [];
+  nextOffsetToken: string | null = null;
+  isLoadingMore = false;
 
   ngOnInit() {
     this.loadLeaderboard();
@@ -20,12 +22,28 @@ export class LeaderboardComponent implements OnInit {
 
   async loadLeaderboard() {
     try {
-      const data = await this._missionService.getLeaderboard();
-      this.topBrawlers = data || [];
+      const page = await this._missionService.getLeaderboard();
+      this.topBrawlers = page.items;
+      this.nextOffsetToken = page.next_offset_token;
     } catch (error: any) {
       console.error('Leaderboard error:', error);
     } finally {
       this.cdr.detectChanges();
     }
   }
+
+  async loadMore() {
+    if (!this.nextOffsetToken || this.isLoadingMore) return;
+    this.isLoadingMore = true;
+    try {
+      const page = await this._missionService.getLeaderboard(this.nextOffsetToken);
+      this.topBrawlers = [...this.topBrawlers, ...page.items];
+      this.nextOffsetToken = page.next_offset_token;
+    } catch (error: any) {
+      console.error('Leaderboard error:', error);
+    } finally {
+      this.isLoadingMore = false;
+      this.cdr.detectChanges();
+    }
+  }
 }
\ No newline at end of file
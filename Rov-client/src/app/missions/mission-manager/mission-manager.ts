@@ -9,6 +9,7 @@ import { PLATFORM_ID } from '@angular/core';
 import { AddMission } from '../../_models/add-mission';
 import { NewMission } from '../../_dialog/new-mission/new-mission';
 import { Mission } from '../../_models/mission';
+import { MissionFilter } from '../../_models/mission-filter';
 import { MissionService } from '../../_services/mission-service';
 import { PassportService } from '../../_services/passport-service';
 
@@ -42,6 +43,8 @@ export class MissionManager implements OnInit {
 
   leadingMissions: Mission[] = [];
   joinedMissions: Mission[] = [];
+  leadingOffsetToken: string | null = null;
+  joinedOffsetToken: string | null = null;
 
   stats = {
     total: 0,
@@ -168,30 +171,23 @@ export class MissionManager implements OnInit {
 
   private async loadMyMission() {
     try {
-      const response: any = await this._missionService.gets({});
-      let allMissions: any[] = [];
-
-      if (Array.isArray(response)) {
-        allMissions = response;
-      } else if (response?.data && Array.isArray(response.data)) {
-        allMissions = response.data;
-      }
-
       const myId = this.myUserId;
-      // 🌟 Filter out Completed from leading (User preference for active list)
-      this.leadingMissions = allMissions.filter((m: any) => m.chief_id == myId && m.status !== 'Completed' && m.status !== 'Failed');
-
-      let joinedIds: number[] = [];
-      if (isPlatformBrowser(this._platformId)) {
-        joinedIds = JSON.parse(localStorage.getItem('my_joined_missions') || '[]') as number[];
-      }
+      // 🌟 Completed and Failed are left out of leading (User preference for active list)
+      const [leading, joined, leadingOpen, joinedOpen] = await Promise.all([
+        this._missionService.gets(this.leadingFilter()),
+        this._missionService.gets({ member: true }),
+        this._missionService.gets({ chief_id: myId, status: 'Open' }),
+        this._missionService.gets({ member: true, status: 'Open' }),
+      ]);
 
-      this.joinedMissions = allMissions.filter((m: any) => {
-        // Specific request to hide Mission #9
-        if (m.id === 9) return false;
-        return m.chief_id != myId && joinedIds.includes(m.id);
-      });
+      this.leadingMissions = leading.items;
+      this.leadingOffsetToken = leading.next_offset_token;
+      this.joinedMissions = this.withoutHidden(joined.items);
+      this.joinedOffsetToken = joined.next_offset_token;
 
+      this.stats.leading = leading.total;
+      this.stats.joined = joined.total;
+      this.stats.open = leadingOpen.total + joinedOpen.total;
       this.calculateStats();
       this.cdr.detectChanges();
     } catch (error: any) {
@@ -199,12 +195,42 @@ export class MissionManager implements OnInit {
     }
   }
 
+  async loadMoreLeading() {
+    if (!this.leadingOffsetToken) return;
+    try {
+      const page = await this._missionService.gets(this.leadingFilter(), this.leadingOffsetToken);
+      this.leadingMissions = [...this.leadingMissions, ...page.items];
+      this.leadingOffsetToken = page.next_offset_token;
+      this.cdr.detectChanges();
+    } catch (error: any) {
+      console.error('❌ Error loading missions:', error);
+    }
+  }
+
+  async loadMoreJoined() {
+    if (!this.joinedOffsetToken) return;
+    try {
+      const page = await this._missionService.gets({ member: true }, this.joinedOffsetToken);
+      this.joinedMissions = [...this.joinedMissions, ...this.withoutHidden(page.items)];
+      this.joinedOffsetToken = page.next_offset_token;
+      this.cdr.detectChanges();
+    } catch (error: any) {
+      console.error('❌ Error loading missions:', error);
+    }
+  }
+
+  private leadingFilter(): MissionFilter {
+    return { chief_id: this.myUserId, statuses: 'Open,InProgress' };
+  }
+
+  // Specific request to hide Mission #9
+  private withoutHidden(missions: Mission[]): Mission[] {
+    return missions.filter(m => m.id !== 9);
+  }
+
+  // leading, joined and open come from the page totals in loadMyMission
   private calculateStats() {
-    this.stats.leading = this.leadingMissions.length;
-    this.stats.joined = this.joinedMissions.length;
     this.stats.total = this.stats.leading + this.stats.joined;
-    this.stats.open = [...this.leadingMissions, ...this.joinedMissions]
-      .filter(m => m.status === 'Open').length;
   }
 
   openDialog() {
@@ -40,6 +40,12 @@ export class Missions implements OnInit, OnDestroy {
   missions: Mission[] = [];
   topBrawlers: BrawlerProfile[] = [];
 
+  // Polling refreshes the first page; pages pulled in with "Load more" are kept on top of it
+  private _firstPage: Mission[] = [];
+  private _morePages: Mission[] = [];
+  nextOffsetToken: string | null = null;
+  isLoadingMore = false;
+
   constructor() { }
 
   ngOnInit() {
@@ -69,7 +75,7 @@ export class Missions implements OnInit, OnDestroy {
         return from(this._missionService.gets(this.filter)).pipe(
           catchError(err => {
             console.error('Polling error:', err);
-            return of([]); // ✅ ใช้ of([]) เพื่อส่งอาเรย์ว่างกลับไป แทนที่จะ error แตก
+            return of(null); // ✅ ใช้ of(null) เพื่อให้หน้าจอเคลียร์ค่าเก่า แทนที่จะ error แตก
           })
         );
       }),
@@ -79,10 +85,14 @@ export class Missions implements OnInit, OnDestroy {
         if (this.isLoading) this.isLoading = false;
       }),
 
-      map(results => {
+      map(page => {
         // ✅ แก้บั๊ก: ของเดิมถ้า results ว่าง มัน return null ทำให้หน้าจอไม่เคลียร์ค่าเก่า
         // ของใหม่: ส่งไป process เสมอ เพื่อให้หน้าจออัปเดตว่าเป็น "ไม่พบข้อมูล"
-        return this.processMissionData(results || []);
+        this._firstPage = page?.items || [];
+        if (this._morePages.length === 0) {
+          this.nextOffsetToken = page?.next_offset_token || null;
+        }
+        return this.processMissionData(this.loadedMissions());
       })
     ).subscribe(filteredMissions => {
       // อัปเดตข้อมูลเข้าตัวแปร missions เพื่อแสดงผล
@@ -93,8 +103,10 @@ export class Missions implements OnInit, OnDestroy {
   // Fallback for SSR
   async fetchMissions() {
     try {
-      const results = await this._missionService.gets(this.filter);
-      this.missions = this.processMissionData(results);
+      const page = await this._missionService.gets(this.filter);
+      this._firstPage = page.items;
+      this.nextOffsetToken = page.next_offset_token;
+      this.missions = this.processMissionData(page.items);
     } catch (error) {
       console.error('SSR Fetch error:', error);
       this.missions = []; // ถ้า Error ให้เคลียร์เป็นว่าง
@@ -103,6 +115,27 @@ export class Missions implements OnInit, OnDestroy {
     }
   }
 
+  async loadMore() {
+    if (!this.nextOffsetToken || this.isLoadingMore) return;
+    this.isLoadingMore = true;
+    try {
+      const page = await this._missionService.gets(this.filter, this.nextOffsetToken);
+      this._morePages = [...this._morePages, ...page.items];
+      this.nextOffsetToken = page.next_offset_token;
+      this.missions = this.processMissionData(this.loadedMissions());
+    } catch (error) {
+      console.error('Load more error:', error);
+    } finally {
+      this.isLoadingMore = false;
+    }
+  }
+
+  // First page plus whatever "Load more" added, without the rows that shifted into the first page since
+  private loadedMissions(): Mission[] {
+    const firstPageIds = new Set(this._firstPage.map(m => m.id));
+    return [...this._firstPage, ...this._morePages.filter(m => !firstPageIds.has(m.id))];
+  }
+
   // Extract filtering logic
   private processMissionData(results: Mission[]): Mission[] {
     let joinedIds: number[] = [];
@@ -122,8 +155,8 @@ export class Missions implements OnInit, OnDestroy {
 
   async loadLeaderboard() {
     try {
-      const data = await this._missionService.getLeaderboard();
-      this.topBrawlers = data.sort((a: BrawlerProfile, b: BrawlerProfile) => b.total_points - a.total_points);
+      const page = await this._missionService.getLeaderboard();
+      this.topBrawlers = page.items.sort((a: BrawlerProfile, b: BrawlerProfile) => b.total_points - a.total_points);
     } catch (error) {
       console.error('Leaderboard error:', error);
     }
@@ -136,6 +169,8 @@ export class Missions implements OnInit, OnDestroy {
   onSubmit() {
     // ✅ ถูกต้อง: ไม่มีการสั่ง isLoading = true หน้าจะไม่กระพริบ
     // แค่สั่งให้ reset timer และดึงข้อมูลใหม่ทันที
+    // The filter may have changed, so pages loaded for the old one no longer apply
+    this._morePages = [];
     this.refresh$.next();
   }
 
@@ -1,10 +1,10 @@
-import { Component, inject, computed, Signal, OnInit, ChangeDetectorRef, PLATFORM_ID } from '@angular/core';
+import { Component, inject, computed, Signal, OnInit, ChangeDetectorRef } from '@angular/core';
 import { PassportService } from '../_services/passport-service';
 import { MatDialog } from '@angular/material/dialog';
 import { UserService } from '../_services/user-service';
 import { UploadPhoto } from '../_dialog/upload-photo/upload-photo';
 import { EditProfileDialog } from '../_dialog/edit-profile/edit-profile';
-import { CommonModule, isPlatformBrowser } from '@angular/common';
+import { CommonModule } from '@angular/common';
 import { MatButtonModule } from '@angular/material/button';
 import { MatIconModule } from '@angular/material/icon';
 import { getAvatar } from '../_helpers/avatar';
@@ -38,7 +38,6 @@ export class Profile implements OnInit {
   private _dialog = inject(MatDialog)
   private _router = inject(Router)
   private cdr = inject(ChangeDetectorRef)
-  private _platformId = inject(PLATFORM_ID);
 
   constructor() {
     this.avatar_url = computed(() => this._passportService.image())
@@ -61,27 +60,14 @@ export class Profile implements OnInit {
       this.stats.points = brawler.total_points;
 
       // 2. Get Rank from Leaderboard
-      const leaderboard = await this._missionService.getLeaderboard();
-      const meIndex = leaderboard.findIndex((b: any) => b.id === brawler.id);
-      this.stats.rank = meIndex !== -1 ? meIndex + 1 : 0;
-
-      // 3. Get Missions Count
-      const myId = brawler.id;
-      let joinedIds: any[] = [];
-      if (isPlatformBrowser(this._platformId)) {
-        joinedIds = JSON.parse(localStorage.getItem('my_joined_missions') || '[]');
-      }
-
-      // Fetch all missions
-      const allMissions: any = await this._missionService.gets({});
-      let missionsList: any[] = [];
-      if (Array.isArray(allMissions)) missionsList = allMissions;
-      else if (allMissions && allMissions.data) missionsList = allMissions.data;
+      const myRank = await this._missionService.getMyRank();
+      this.stats.rank = myRank.me.rank;
 
-      const leadingCount = missionsList.filter((m: any) => m.chief_id == myId).length;
-      const joinedCount = missionsList.filter((m: any) => m.chief_id != myId && joinedIds.includes(m.id)).length;
+      // 3. Get Missions Count from the page totals
+      const leading = await this._missionService.getMyMissions();
+      const joined = await this._missionService.gets({ member: true });
 
-      this.stats.missions = leadingCount + joinedCount;
+      this.stats.missions = leading.total + joined.total;
 
       this.cdr.detectChanges();
     } catch (error) {
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::achievements::AchievementEntity,
+    repositories::achievements::AchievementRepository,
+    value_objects::{
+        achievement_criteria::AchievementCriteria,
+        achievement_model::{AchievementCatalogModel, UnlockedAchievementModel},
+    },
+};
+
+pub struct AchievementUseCase<T>
+where
+    T: AchievementRepository + Send + Sync,
+{
+    achievement_repository: Arc<T>,
+}
+
+impl<T> AchievementUseCase<T>
+where
+    T: AchievementRepository + Send + Sync,
+{
+    pub fn new(achievement_repository: Arc<T>) -> Self {
+        Self {
+            achievement_repository,
+        }
+    }
+
+    pub async fn get_catalog(&self) -> Result<Vec<AchievementCatalogModel>> {
+        self.achievement_repository.get_catalog().await
+    }
+
+    pub async fn get_unlocked(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievementModel>> {
+        self.achievement_repository.get_unlocked(brawler_id).await
+    }
+
+    /// Checks every badge the brawler doesn't own yet against their current stats
+    /// and unlocks the ones they now qualify for.
+    pub async fn evaluate(&self, brawler_id: i32) -> Result<Vec<AchievementEntity>> {
+        let unlocked_ids = self
+            .achievement_repository
+            .get_unlocked_ids(brawler_id)
+            .await?;
+        let stats = self
+            .achievement_repository
+            .get_brawler_stats(brawler_id)
+            .await?;
+
+        let mut newly_unlocked = Vec::new();
+        for achievement in self.achievement_repository.get_all().await? {
+            if unlocked_ids.contains(&achievement.id) {
+                continue;
+            }
+            let criteria = AchievementCriteria::try_from_str(&achievement.criteria)?;
+            if stats.value_of(&criteria) >= achievement.threshold as i64 {
+                newly_unlocked.push(achievement);
+            }
+        }
+
+        if !newly_unlocked.is_empty() {
+            let ids = newly_unlocked.iter().map(|a| a.id).collect();
+            self.achievement_repository.unlock(brawler_id, ids).await?;
+        }
+
+        Ok(newly_unlocked)
+    }
+}
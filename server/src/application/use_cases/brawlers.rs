@@ -1,9 +1,22 @@
 use crate::{
+    config::config_model::DotEnvyConfig,
     domain::{
-        entities::brawlers::Brawler, 
-        repositories::brawlers::BrawlerRepository,
+        entities::{
+            brawler_level_ups::BrawlerLevelUpEntity, brawlers::Brawler,
+            point_ledger::PointLedgerEntity,
+        },
+        repositories::{
+            achievements::AchievementRepository, brawlers::BrawlerRepository,
+            rewards::RewardRepository,
+        },
         value_objects::{
-            base64_image::Base64Image, brawler_model::RegisterBrawlerModel,
+            base64_image::Base64Image,
+            brawler_model::RegisterBrawlerModel,
+            leaderboard_model::{
+                LeaderboardEntryModel, LeaderboardFilter, LeaderboardMeModel, LeaderboardRowModel,
+            },
+            page_model::{Page, PageRequest},
+            tier_progress_model::TierProgressModel,
             uploaded_image::UploadedImage,
         },
     },
@@ -14,30 +27,167 @@ use crate::{
     },
 };
 use anyhow::Result;
+use chrono::Utc;
 use std::sync::Arc;
 
-pub struct BrawlersUseCase<T>
+const DEFAULT_LEADERBOARD_LIMIT: i64 = 10;
+const MAX_LEADERBOARD_LIMIT: i64 = 100;
+const MAX_LEADERBOARD_AROUND: i64 = 5;
+const DEFAULT_POINT_HISTORY_LIMIT: i64 = 20;
+const MAX_POINT_HISTORY_LIMIT: i64 = 100;
+
+pub struct BrawlersUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: RewardRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    achievement_repository: Arc<T2>,
+    reward_repository: Arc<T3>,
+    config: Arc<DotEnvyConfig>,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2, T3> BrawlersUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: RewardRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        achievement_repository: Arc<T2>,
+        reward_repository: Arc<T3>,
+        config: Arc<DotEnvyConfig>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            achievement_repository,
+            reward_repository,
+            config,
+        }
+    }
+
+    pub async fn get_leaderboard(
+        &self,
+        filter: &LeaderboardFilter,
+    ) -> Result<Page<LeaderboardEntryModel>> {
+        let page_request = PageRequest::resolve(
+            filter.page,
+            filter.limit,
+            filter.offset_token.as_deref(),
+            DEFAULT_LEADERBOARD_LIMIT,
+            MAX_LEADERBOARD_LIMIT,
+        )?;
+
+        let window = filter.window.unwrap_or_default();
+        let total = self.brawler_repository.count_leaderboard().await?;
+        let rows = self
+            .brawler_repository
+            .get_leaderboard(
+                window.starts_at(Utc::now().naive_utc()),
+                page_request.limit,
+                page_request.offset,
+            )
+            .await?;
+
+        let thresholds = &self.config.tier.thresholds;
+        let entries = rows
+            .into_iter()
+            .map(|row| Self::to_entry(row, thresholds))
+            .collect();
+
+        Ok(Page::new(entries, total, page_request))
+    }
+
+    pub async fn get_my_rank(
+        &self,
+        brawler_id: i32,
+        filter: &LeaderboardFilter,
+    ) -> Result<LeaderboardMeModel> {
+        let around = filter.around.unwrap_or(1).clamp(1, MAX_LEADERBOARD_AROUND);
+        let window = filter.window.unwrap_or_default();
+
+        let rows = self
+            .brawler_repository
+            .get_leaderboard_around(brawler_id, window.starts_at(Utc::now().naive_utc()), around)
+            .await?;
+
+        let thresholds = &self.config.tier.thresholds;
+        let mut me = None;
+        let mut above = Vec::new();
+        let mut below = Vec::new();
+        for row in rows {
+            let entry = Self::to_entry(row, thresholds);
+            if entry.brawler.id == brawler_id {
+                me = Some(entry);
+            } else if me.is_none() {
+                above.push(entry);
+            } else {
+                below.push(entry);
+            }
+        }
+
+        let me = me.ok_or_else(|| anyhow::anyhow!("Brawler not found on the leaderboard"))?;
+        Ok(LeaderboardMeModel {
+            window,
+            me,
+            above,
+            below,
+        })
     }
 
-    pub async fn get_leaderboard(&self) -> Result<Vec<Brawler>, String> {
-        self.brawler_repository.get_leaderboard().await
+    fn to_entry(row: LeaderboardRowModel, thresholds: &[i32]) -> LeaderboardEntryModel {
+        LeaderboardEntryModel {
+            tier: TierProgressModel::from_points(row.total_points, thresholds),
+            brawler: row,
+        }
     }
 
     pub async fn get_me(&self, brawler_id: i32) -> Result<Brawler> {
         let entity = self.brawler_repository.find_by_id(brawler_id).await?;
-        Ok(entity.into())
+        let mut brawler = Brawler::from(entity).with_tier(&self.config.tier.thresholds);
+        brawler.badges = self.achievement_repository.get_unlocked(brawler_id).await?;
+        brawler.cosmetics = self.reward_repository.get_owned(brawler_id).await?;
+        Ok(brawler)
+    }
+
+    pub async fn get_level_ups(
+        &self,
+        brawler_id: i32,
+        unseen_only: bool,
+    ) -> Result<Vec<BrawlerLevelUpEntity>> {
+        self.brawler_repository
+            .get_level_ups(brawler_id, unseen_only)
+            .await
+    }
+
+    pub async fn get_point_history(
+        &self,
+        brawler_id: i32,
+        page: Option<i64>,
+        limit: Option<i64>,
+        offset_token: Option<&str>,
+    ) -> Result<Page<PointLedgerEntity>> {
+        let page_request = PageRequest::resolve(
+            page,
+            limit,
+            offset_token,
+            DEFAULT_POINT_HISTORY_LIMIT,
+            MAX_POINT_HISTORY_LIMIT,
+        )?;
+
+        let total = self.brawler_repository.count_point_history(brawler_id).await?;
+        let history = self
+            .brawler_repository
+            .get_point_history(brawler_id, page_request.limit, page_request.offset)
+            .await?;
+
+        Ok(Page::new(history, total, page_request))
+    }
+
+    pub async fn mark_level_ups_seen(&self, brawler_id: i32) -> Result<usize> {
+        self.brawler_repository.mark_level_ups_seen(brawler_id).await
     }
 
     pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> Result<Passport> {
@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::domain::value_objects::page_model::{Page, PageRequest};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub id: String,
@@ -14,12 +16,15 @@ pub struct ChatMessage {
     pub created_at: DateTime<Utc>,
 }
 
-static CHAT_STORE: OnceLock<Arc<Mutex<HashMap<i32, Vec<ChatMessage>>>>> = OnceLock::new();
+type ChatStore = Arc<Mutex<HashMap<i32, Vec<ChatMessage>>>>;
+
+static CHAT_STORE: OnceLock<ChatStore> = OnceLock::new();
 
-fn get_chat_store() -> Arc<Mutex<HashMap<i32, Vec<ChatMessage>>>> {
+fn get_chat_store() -> ChatStore {
     CHAT_STORE.get_or_init(|| Arc::new(Mutex::new(HashMap::new()))).clone()
 }
 
+#[derive(Default)]
 pub struct ChatUseCase;
 
 impl ChatUseCase {
@@ -27,20 +32,30 @@ impl ChatUseCase {
         Self
     }
 
-    pub async fn get_messages(&self, mission_id: i32) -> anyhow::Result<Vec<ChatMessage>> {
+    /// Page 1 holds the newest messages; each page is in sending order.
+    pub async fn get_messages(
+        &self,
+        mission_id: i32,
+        page_request: PageRequest,
+    ) -> anyhow::Result<Page<ChatMessage>> {
         let store_arc = get_chat_store();
         let store = store_arc.lock().unwrap();
-        
-        // Return empty vec if no messages yet
-        let messages = store.get(&mission_id).cloned().unwrap_or_else(Vec::new);
-        Ok(messages)
+
+        // Empty page if no messages yet
+        let messages = store.get(&mission_id).map(Vec::as_slice).unwrap_or_default();
+        let total = messages.len() as i64;
+        let end = (total - page_request.offset).max(0);
+        let start = (end - page_request.limit).max(0);
+        let items = messages[start as usize..end as usize].to_vec();
+
+        Ok(Page::new(items, total, page_request))
     }
 
     pub async fn send_message(&self, mission_id: i32, sender_id: i32, sender_name: String, content: String) -> anyhow::Result<ChatMessage> {
         let store_arc = get_chat_store();
         let mut store = store_arc.lock().unwrap();
         
-        let messages = store.entry(mission_id).or_insert_with(Vec::new);
+        let messages = store.entry(mission_id).or_default();
         
         let msg = ChatMessage {
             id: Uuid::new_v4().to_string(),
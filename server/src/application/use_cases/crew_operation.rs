@@ -1,80 +1,310 @@
-use crate::domain::{
-    entities::crew_memberships::CrewMemberShips,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::{achievements::AchievementUseCase, notifications::NotificationUseCase},
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::{
+            crew_memberships::CrewMemberShips,
+            join_requests::{AddJoinRequestEntity, JoinRequestEntity},
+            mission_bans::AddMissionBanEntity,
+            mission_waitlist::MissionWaitlistEntity,
+            missions::MissionEntity,
+        },
+        repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::{
+            crew_model::{KickCrewModel, MAX_KICK_REASON_LENGTH, TransferChiefModel},
+            join_request_statuses::{JoinOutcomes, JoinRequestStatuses},
+            lanes::Lanes,
+            mission_statuses::MissionStatuses,
+            notification_kinds::NotificationKinds,
+            rank_requirement_model::RankRequirementModel,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::{error, warn};
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T1, T2, T3, T4>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    achievement_repository: Arc<T3>,
+    notification_repository: Arc<T4>,
+    config: Arc<DotEnvyConfig>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4> CrewOperationUseCase<T1, T2, T3, T4>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        achievement_repository: Arc<T3>,
+        notification_repository: Arc<T4>,
+        config: Arc<DotEnvyConfig>,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            achievement_repository,
+            notification_repository,
+            config,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    async fn ensure_daily_limit(&self, brawler_id: i32) -> Result<()> {
         let daily_count = self.mission_viewing_repository.get_daily_interaction_count(brawler_id).await?;
         if daily_count >= 3 {
              return Err(anyhow::anyhow!("Daily mission limit (3) reached. You cannot create or join more missions today."));
         }
+        Ok(())
+    }
+
+    /// Checks the rejoin ban and the mission's rank requirement as they stand now.
+    async fn ensure_eligible(&self, mission: &MissionEntity, brawler_id: i32) -> Result<()> {
+        if self.crew_operation_repository.is_banned(mission.id, brawler_id).await? {
+            return Err(anyhow::anyhow!(
+                "You were removed from this mission by its chief and cannot rejoin it"
+            ));
+        }
 
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+        let rank_requirement = mission.rank_requirement();
+        if rank_requirement != RankRequirementModel::default() {
+            let total_points = self.mission_viewing_repository.get_total_points(brawler_id).await?;
+            rank_requirement.ensure_met(total_points, &self.config.tier.thresholds)?;
+        }
+        Ok(())
+    }
 
+    /// Joins right away, files a pending request when the chief approves crew by hand,
+    /// or queues the brawler on the waitlist when the mission is full.
+    /// `lane` is claimed on a direct join only; approved and waitlisted brawlers claim later.
+    pub async fn join(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        lane: Option<Lanes>,
+    ) -> Result<JoinOutcomes> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
+        // With approval on, the daily limit and capacity are checked when the chief accepts
+        if !mission.requires_approval {
+            self.ensure_daily_limit(brawler_id).await?;
+        }
+
         if mission.chief_id == brawler_id {
             return Err(anyhow::anyhow!(
                 "Chiefs cannot join their own missions as crew members"
             ));
         }
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-        let mission_status_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
+        let mission_status_condition = mission.status == MissionStatuses::Open
+            || mission.status == MissionStatuses::Failed;
         if !mission_status_condition {
             return Err(anyhow::anyhow!("Mission is not joinable"));
         }
-        let crew_count_condition = crew_count < max_crew_per_mission;
-        if !crew_count_condition {
-            return Err(anyhow::anyhow!("Mission is full"));
+        self.ensure_eligible(&mission, brawler_id).await?;
+
+        if mission.requires_approval {
+            let request_id = self.request_to_join(&mission, brawler_id).await?;
+            return Ok(JoinOutcomes::Requested { request_id });
         }
 
-        self.crew_operation_repository
-            .join(CrewMemberShips {
-                mission_id,
+        // Capacity is checked by the repository while holding the mission row lock
+        let outcome = self
+            .crew_operation_repository
+            .join_or_waitlist(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                lane,
+                mission.crew_capacity(self.config.max_crew_per_mission),
+            )
+            .await?;
+
+        if outcome == JoinOutcomes::Joined
+            && let Err(e) = AchievementUseCase::new(Arc::clone(&self.achievement_repository))
+                .evaluate(brawler_id)
+                .await
+        {
+            error!("Achievement evaluation for brawler ({}) failed: {}", brawler_id, e);
+        }
+
+        Ok(outcome)
+    }
+
+    async fn request_to_join(&self, mission: &MissionEntity, brawler_id: i32) -> Result<i32> {
+        let crew_ids = self.mission_viewing_repository.get_crew_ids(mission.id).await?;
+        if crew_ids.contains(&brawler_id) {
+            return Err(anyhow::anyhow!("You are already in this mission's crew"));
+        }
+        let pending = self
+            .crew_operation_repository
+            .get_join_requests_by_brawler(brawler_id, JoinRequestStatuses::Pending)
+            .await?;
+        if pending.iter().any(|request| request.mission_id == mission.id) {
+            return Err(anyhow::anyhow!("You already have a pending request for this mission"));
+        }
+
+        let request_id = self
+            .crew_operation_repository
+            .add_join_request(AddJoinRequestEntity {
+                mission_id: mission.id,
                 brawler_id,
+                status: JoinRequestStatuses::Pending,
             })
             .await?;
 
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[mission.chief_id],
+                NotificationKinds::JoinRequested,
+                Some(mission.id),
+                &format!("A brawler asked to join mission \"{}\"", mission.name),
+            )
+            .await
+        {
+            error!("Join request ({}) notification failed: {}", request_id, e);
+        }
+
+        Ok(request_id)
+    }
+
+    /// Requests for one of the chief's missions, pending ones unless another status is asked for.
+    pub async fn get_mission_join_requests(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        status: Option<JoinRequestStatuses>,
+    ) -> Result<Vec<JoinRequestEntity>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can view join requests"));
+        }
+
+        self.crew_operation_repository
+            .get_join_requests_by_mission(mission_id, status.unwrap_or(JoinRequestStatuses::Pending))
+            .await
+    }
+
+    pub async fn get_my_join_requests(
+        &self,
+        brawler_id: i32,
+        status: Option<JoinRequestStatuses>,
+    ) -> Result<Vec<JoinRequestEntity>> {
+        self.crew_operation_repository
+            .get_join_requests_by_brawler(brawler_id, status.unwrap_or(JoinRequestStatuses::Pending))
+            .await
+    }
+
+    /// Fetches a request together with its mission, making sure `chief_id` runs that mission.
+    async fn get_request_for_chief(
+        &self,
+        request_id: i32,
+        chief_id: i32,
+    ) -> Result<(JoinRequestEntity, MissionEntity)> {
+        let request = self.crew_operation_repository.get_join_request(request_id).await?;
+        let mission = self.mission_viewing_repository.get_one(request.mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can decide on join requests"));
+        }
+        if request.status != JoinRequestStatuses::Pending {
+            return Err(anyhow::anyhow!("Join request is no longer pending"));
+        }
+        Ok((request, mission))
+    }
+
+    pub async fn accept_join_request(&self, request_id: i32, chief_id: i32) -> Result<()> {
+        let (request, mission) = self.get_request_for_chief(request_id, chief_id).await?;
+
+        // Accepting counts as the requester's join for today
+        let daily_count = self
+            .mission_viewing_repository
+            .get_daily_interaction_count(request.brawler_id)
+            .await?;
+        if daily_count >= 3 {
+            return Err(anyhow::anyhow!(
+                "Requester reached the daily mission limit (3) and cannot join more missions today."
+            ));
+        }
+
+        self.crew_operation_repository
+            .accept_join_request(request_id, mission.crew_capacity(self.config.max_crew_per_mission))
+            .await?;
+
+        if let Err(e) = AchievementUseCase::new(Arc::clone(&self.achievement_repository))
+            .evaluate(request.brawler_id)
+            .await
+        {
+            error!("Achievement evaluation for brawler ({}) failed: {}", request.brawler_id, e);
+        }
+
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[request.brawler_id],
+                NotificationKinds::JoinRequestAccepted,
+                Some(mission.id),
+                &format!("Your request to join mission \"{}\" was accepted", mission.name),
+            )
+            .await
+        {
+            error!("Join request ({}) accepted notification failed: {}", request_id, e);
+        }
+
+        Ok(())
+    }
+
+    pub async fn reject_join_request(&self, request_id: i32, chief_id: i32) -> Result<()> {
+        let (request, mission) = self.get_request_for_chief(request_id, chief_id).await?;
+
+        self.crew_operation_repository
+            .close_join_request(request_id, JoinRequestStatuses::Rejected)
+            .await?;
+
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[request.brawler_id],
+                NotificationKinds::JoinRequestRejected,
+                Some(mission.id),
+                &format!("Your request to join mission \"{}\" was rejected", mission.name),
+            )
+            .await
+        {
+            error!("Join request ({}) rejected notification failed: {}", request_id, e);
+        }
+
         Ok(())
     }
 
+    pub async fn withdraw_join_request(&self, request_id: i32, brawler_id: i32) -> Result<()> {
+        let request = self.crew_operation_repository.get_join_request(request_id).await?;
+        // Someone else's request is reported as missing rather than forbidden
+        if request.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("Join request not found"));
+        }
+
+        self.crew_operation_repository
+            .close_join_request(request_id, JoinRequestStatuses::Withdrawn)
+            .await
+    }
+
     pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        let leaving_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
+        let leaving_condition = mission.status == MissionStatuses::Open
+            || mission.status == MissionStatuses::Failed;
         if !leaving_condition {
             return Err(anyhow::anyhow!("Mission is not leavable"));
         }
@@ -85,6 +315,497 @@ where
             })
             .await?;
 
+        // The brawler has left either way; promotion is best-effort
+        if let Err(e) = self.promote_waitlisted(&mission).await {
+            error!("Waitlist promotion for mission ({}) failed: {}", mission_id, e);
+        }
+
+        Ok(())
+    }
+
+    /// Switches the brawler's lane in a mission they crew, or frees it with `None`.
+    pub async fn claim_lane(&self, mission_id: i32, brawler_id: i32, lane: Option<Lanes>) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let claim_condition = mission.status == MissionStatuses::Open
+            || mission.status == MissionStatuses::Failed;
+        if !claim_condition {
+            return Err(anyhow::anyhow!("Lanes can only change while the mission is open"));
+        }
+
+        // Slot availability is checked by the repository while holding the mission row lock
+        self.crew_operation_repository
+            .claim_lane(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                lane,
+            )
+            .await
+    }
+
+    /// Ensures `chief_id` runs the mission and its crew can still change.
+    async fn get_mission_for_chief(&self, mission_id: i32, chief_id: i32) -> Result<MissionEntity> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can manage the crew"));
+        }
+        if mission.status == MissionStatuses::Completed || mission.status == MissionStatuses::Cancelled {
+            return Err(anyhow::anyhow!(
+                "The crew of a {} mission can no longer change",
+                mission.status
+            ));
+        }
+        Ok(mission)
+    }
+
+    /// Removes a crew member and bans them from rejoining this mission. Like leaving, this
+    /// only works while the mission is Open or Failed, never mid-run.
+    pub async fn kick(&self, mission_id: i32, chief_id: i32, kick_crew_model: KickCrewModel) -> Result<()> {
+        let mission = self.get_mission_for_chief(mission_id, chief_id).await?;
+        if mission.status != MissionStatuses::Open && mission.status != MissionStatuses::Failed {
+            return Err(anyhow::anyhow!(
+                "Crew can only be removed while the mission is Open or Failed"
+            ));
+        }
+        if kick_crew_model.brawler_id == chief_id {
+            return Err(anyhow::anyhow!("Chiefs cannot remove themselves"));
+        }
+
+        let reason = kick_crew_model
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        if reason
+            .as_ref()
+            .is_some_and(|reason| reason.chars().count() > MAX_KICK_REASON_LENGTH)
+        {
+            return Err(anyhow::anyhow!(
+                "Reason must be at most {} characters",
+                MAX_KICK_REASON_LENGTH
+            ));
+        }
+
+        self.crew_operation_repository
+            .kick(AddMissionBanEntity {
+                mission_id,
+                brawler_id: kick_crew_model.brawler_id,
+                banned_by: chief_id,
+                reason: reason.clone(),
+            })
+            .await?;
+
+        let message = match reason {
+            Some(reason) => format!(
+                "You were removed from mission \"{}\" by its chief: {}",
+                mission.name, reason
+            ),
+            None => format!("You were removed from mission \"{}\" by its chief", mission.name),
+        };
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[kick_crew_model.brawler_id],
+                NotificationKinds::CrewRemoved,
+                Some(mission_id),
+                &message,
+            )
+            .await
+        {
+            error!("Crew removal notification for mission ({}) failed: {}", mission_id, e);
+        }
+
+        if let Err(e) = self.promote_waitlisted(&mission).await {
+            error!("Waitlist promotion for mission ({}) failed: {}", mission_id, e);
+        }
+
+        Ok(())
+    }
+
+    /// Makes a crew member the chief; the old chief takes their place in the crew. Allowed
+    /// while InProgress too, so a chief who has to drop out mid-run can hand the mission over.
+    /// The new chief's lane is freed, since chiefs don't hold one.
+    pub async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transfer_chief_model: TransferChiefModel,
+    ) -> Result<()> {
+        let mission = self.get_mission_for_chief(mission_id, chief_id).await?;
+        if transfer_chief_model.brawler_id == chief_id {
+            return Err(anyhow::anyhow!("You are already the chief of this mission"));
+        }
+
+        let freed_lane = self
+            .crew_operation_repository
+            .transfer_chief(mission_id, chief_id, transfer_chief_model.brawler_id)
+            .await?;
+
+        let message = match freed_lane {
+            Some(lane) => format!(
+                "You are now the chief of mission \"{}\" and no longer hold the {} lane",
+                mission.name, lane
+            ),
+            None => format!("You are now the chief of mission \"{}\"", mission.name),
+        };
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[transfer_chief_model.brawler_id],
+                NotificationKinds::ChiefTransferred,
+                Some(mission_id),
+                &message,
+            )
+            .await
+        {
+            error!("Chief transfer notification for mission ({}) failed: {}", mission_id, e);
+        }
+
         Ok(())
     }
+
+    pub async fn get_waitlist(&self, mission_id: i32) -> Result<Vec<MissionWaitlistEntity>> {
+        self.crew_operation_repository.get_waitlist(mission_id).await
+    }
+
+    pub async fn leave_waitlist(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+        self.crew_operation_repository
+            .leave_waitlist(CrewMemberShips {
+                mission_id,
+                brawler_id,
+            })
+            .await
+    }
+
+    /// Fills free crew slots from the front of the waitlist. Brawlers who can't join right now,
+    /// e.g. at their daily limit or below a rank requirement tightened since they queued, keep
+    /// their place and are skipped. Returns the promoted brawler ids.
+    pub async fn promote_waitlisted(&self, mission: &MissionEntity) -> Result<Vec<i32>> {
+        let mut promoted = Vec::new();
+        if mission.status != MissionStatuses::Open && mission.status != MissionStatuses::Failed {
+            return Ok(promoted);
+        }
+
+        let crew_capacity = mission.crew_capacity(self.config.max_crew_per_mission);
+        let mut free_slots = crew_capacity as i64
+            - self.mission_viewing_repository.crew_counting(mission.id).await?;
+
+        for entry in self.crew_operation_repository.get_waitlist(mission.id).await? {
+            if free_slots <= 0 {
+                break;
+            }
+            if self.ensure_daily_limit(entry.brawler_id).await.is_err() {
+                continue;
+            }
+            if let Err(e) = self.ensure_eligible(mission, entry.brawler_id).await {
+                warn!(
+                    "Waitlist promotion of brawler ({}) into mission ({}) skipped: {}",
+                    entry.brawler_id, mission.id, e
+                );
+                continue;
+            }
+
+            // Another join may have taken the slot since we counted; the lock decides
+            if let Err(e) = self
+                .crew_operation_repository
+                .join(
+                    CrewMemberShips {
+                        mission_id: mission.id,
+                        brawler_id: entry.brawler_id,
+                    },
+                    crew_capacity,
+                )
+                .await
+            {
+                warn!(
+                    "Waitlist promotion of brawler ({}) into mission ({}) skipped: {}",
+                    entry.brawler_id, mission.id, e
+                );
+                continue;
+            }
+            free_slots -= 1;
+            promoted.push(entry.brawler_id);
+
+            if let Err(e) = AchievementUseCase::new(Arc::clone(&self.achievement_repository))
+                .evaluate(entry.brawler_id)
+                .await
+            {
+                error!("Achievement evaluation for brawler ({}) failed: {}", entry.brawler_id, e);
+            }
+            if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+                .notify(
+                    &[entry.brawler_id],
+                    NotificationKinds::WaitlistPromoted,
+                    Some(mission.id),
+                    &format!(
+                        "A slot opened up in mission \"{}\" and you moved from the waitlist into the crew",
+                        mission.name
+                    ),
+                )
+                .await
+            {
+                error!("Waitlist promotion notification for brawler ({}) failed: {}", entry.brawler_id, e);
+            }
+        }
+
+        Ok(promoted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::domain::{
+        repositories::{
+            achievements::MockAchievementRepository,
+            crew_operation::MockCrewOperationRepository,
+            mission_viewing::MockMissionViewingRepository,
+            notifications::MockNotificationRepository,
+        },
+        value_objects::rank_tiers::RankTiers,
+    };
+
+    const CHIEF_ID: i32 = 1;
+    const BRAWLER_ID: i32 = 7;
+
+    fn mission(status: MissionStatuses) -> MissionEntity {
+        MissionEntity {
+            id: 1,
+            name: "Push mid".to_string(),
+            description: None,
+            status,
+            chief_id: CHIEF_ID,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            deleted_at: None,
+            max_crew: 3,
+            difficulty: "NORMAL".to_string(),
+            base_points: 3,
+            due_date: None,
+            archived_at: None,
+            series_id: None,
+            requires_approval: false,
+            min_rank: None,
+            max_rank: None,
+            min_total_points: None,
+            finished_at: None,
+        }
+    }
+
+    fn use_case(
+        crew_operation_repository: MockCrewOperationRepository,
+        mission_viewing_repository: MockMissionViewingRepository,
+        achievement_repository: MockAchievementRepository,
+        notification_repository: MockNotificationRepository,
+    ) -> CrewOperationUseCase<
+        MockCrewOperationRepository,
+        MockMissionViewingRepository,
+        MockAchievementRepository,
+        MockNotificationRepository,
+    > {
+        CrewOperationUseCase::new(
+            Arc::new(crew_operation_repository),
+            Arc::new(mission_viewing_repository),
+            Arc::new(achievement_repository),
+            Arc::new(notification_repository),
+            Arc::new(DotEnvyConfig::for_tests()),
+        )
+    }
+
+    /// A mission with free slots and one waitlisted brawler holding `total_points`.
+    fn waitlist_mocks(
+        banned: bool,
+        total_points: i32,
+    ) -> (MockCrewOperationRepository, MockMissionViewingRepository) {
+        let mut crew_operation_repository = MockCrewOperationRepository::new();
+        crew_operation_repository.expect_get_waitlist().returning(|mission_id| {
+            Ok(vec![MissionWaitlistEntity {
+                id: 1,
+                mission_id,
+                brawler_id: BRAWLER_ID,
+                created_at: NaiveDateTime::default(),
+            }])
+        });
+        crew_operation_repository
+            .expect_is_banned()
+            .returning(move |_, _| Ok(banned));
+
+        let mut mission_viewing_repository = MockMissionViewingRepository::new();
+        mission_viewing_repository.expect_crew_counting().returning(|_| Ok(0));
+        mission_viewing_repository
+            .expect_get_daily_interaction_count()
+            .returning(|_| Ok(0));
+        mission_viewing_repository
+            .expect_get_total_points()
+            .returning(move |_| Ok(total_points));
+
+        (crew_operation_repository, mission_viewing_repository)
+    }
+
+    fn gold_mission() -> MissionEntity {
+        MissionEntity {
+            min_rank: Some(RankTiers::Gold),
+            ..mission(MissionStatuses::Open)
+        }
+    }
+
+    #[tokio::test]
+    async fn promote_waitlisted_skips_brawlers_below_a_tightened_rank_requirement() {
+        let (mut crew_operation_repository, mission_viewing_repository) = waitlist_mocks(false, 149);
+        crew_operation_repository.expect_join().never();
+
+        let promoted = use_case(
+            crew_operation_repository,
+            mission_viewing_repository,
+            MockAchievementRepository::new(),
+            MockNotificationRepository::new(),
+        )
+        .promote_waitlisted(&gold_mission())
+        .await
+        .unwrap();
+
+        assert!(promoted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn promote_waitlisted_skips_banned_brawlers() {
+        let (mut crew_operation_repository, mission_viewing_repository) = waitlist_mocks(true, 500);
+        crew_operation_repository.expect_join().never();
+
+        let promoted = use_case(
+            crew_operation_repository,
+            mission_viewing_repository,
+            MockAchievementRepository::new(),
+            MockNotificationRepository::new(),
+        )
+        .promote_waitlisted(&gold_mission())
+        .await
+        .unwrap();
+
+        assert!(promoted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn promote_waitlisted_joins_brawlers_who_meet_the_requirement() {
+        let (mut crew_operation_repository, mission_viewing_repository) = waitlist_mocks(false, 150);
+        crew_operation_repository
+            .expect_join()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut achievement_repository = MockAchievementRepository::new();
+        achievement_repository
+            .expect_get_unlocked_ids()
+            .returning(|_| Err(anyhow::anyhow!("not under test")));
+        let mut notification_repository = MockNotificationRepository::new();
+        notification_repository
+            .expect_add_many()
+            .returning(|notifications| Ok(notifications.len()));
+
+        let promoted = use_case(
+            crew_operation_repository,
+            mission_viewing_repository,
+            achievement_repository,
+            notification_repository,
+        )
+        .promote_waitlisted(&gold_mission())
+        .await
+        .unwrap();
+
+        assert_eq!(promoted, vec![BRAWLER_ID]);
+    }
+
+    fn viewing_mocks(status: MissionStatuses) -> MockMissionViewingRepository {
+        let mut mission_viewing_repository = MockMissionViewingRepository::new();
+        mission_viewing_repository
+            .expect_get_one()
+            .returning(move |_| Ok(mission(status)));
+        mission_viewing_repository
+    }
+
+    #[tokio::test]
+    async fn kick_is_rejected_while_the_mission_is_in_progress() {
+        let mut crew_operation_repository = MockCrewOperationRepository::new();
+        crew_operation_repository.expect_kick().never();
+
+        let result = use_case(
+            crew_operation_repository,
+            viewing_mocks(MissionStatuses::InProgress),
+            MockAchievementRepository::new(),
+            MockNotificationRepository::new(),
+        )
+        .kick(
+            1,
+            CHIEF_ID,
+            KickCrewModel {
+                brawler_id: BRAWLER_ID,
+                reason: None,
+            },
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Crew can only be removed while the mission is Open or Failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn transfer_chief_is_allowed_while_the_mission_is_in_progress() {
+        let mut crew_operation_repository = MockCrewOperationRepository::new();
+        crew_operation_repository
+            .expect_transfer_chief()
+            .times(1)
+            .returning(|_, _, _| Ok(Some(Lanes::Mid)));
+        let mut notification_repository = MockNotificationRepository::new();
+        notification_repository
+            .expect_add_many()
+            .withf(|notifications| {
+                notifications.len() == 1
+                    && notifications[0].brawler_id == BRAWLER_ID
+                    && notifications[0].message.contains("no longer hold the Mid lane")
+            })
+            .times(1)
+            .returning(|notifications| Ok(notifications.len()));
+
+        let result = use_case(
+            crew_operation_repository,
+            viewing_mocks(MissionStatuses::InProgress),
+            MockAchievementRepository::new(),
+            notification_repository,
+        )
+        .transfer_chief(
+            1,
+            CHIEF_ID,
+            TransferChiefModel {
+                brawler_id: BRAWLER_ID,
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn transfer_chief_is_rejected_once_the_mission_is_completed() {
+        let mut crew_operation_repository = MockCrewOperationRepository::new();
+        crew_operation_repository.expect_transfer_chief().never();
+
+        let result = use_case(
+            crew_operation_repository,
+            viewing_mocks(MissionStatuses::Completed),
+            MockAchievementRepository::new(),
+            MockNotificationRepository::new(),
+        )
+        .transfer_chief(
+            1,
+            CHIEF_ID,
+            TransferChiefModel {
+                brawler_id: BRAWLER_ID,
+            },
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
 }
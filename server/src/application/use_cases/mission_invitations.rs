@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use tracing::error;
+
+use crate::{
+    application::use_cases::{achievements::AchievementUseCase, notifications::NotificationUseCase},
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::mission_invitations::AddMissionInvitationEntity,
+        repositories::{
+            achievements::AchievementRepository, brawlers::BrawlerRepository,
+            crew_operation::CrewOperationRepository,
+            mission_invitations::MissionInvitationRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::{
+            join_request_statuses::JoinRequestStatuses,
+            mission_invitation_model::{
+                DEFAULT_INVITATION_HOURS, InvitationStatuses, InviteBrawlerModel,
+                MAX_INVITATION_HOURS, MissionInvitationModel,
+            },
+            mission_statuses::MissionStatuses,
+            notification_kinds::NotificationKinds,
+            rank_requirement_model::RankRequirementModel,
+        },
+    },
+};
+
+pub struct MissionInvitationUseCase<T1, T2, T3, T4, T5, T6>
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    mission_invitation_repository: Arc<T1>,
+    crew_operation_repository: Arc<T2>,
+    mission_viewing_repository: Arc<T3>,
+    brawler_repository: Arc<T4>,
+    achievement_repository: Arc<T5>,
+    notification_repository: Arc<T6>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T1, T2, T3, T4, T5, T6> MissionInvitationUseCase<T1, T2, T3, T4, T5, T6>
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    pub fn new(
+        mission_invitation_repository: Arc<T1>,
+        crew_operation_repository: Arc<T2>,
+        mission_viewing_repository: Arc<T3>,
+        brawler_repository: Arc<T4>,
+        achievement_repository: Arc<T5>,
+        notification_repository: Arc<T6>,
+        config: Arc<DotEnvyConfig>,
+    ) -> Self {
+        Self {
+            mission_invitation_repository,
+            crew_operation_repository,
+            mission_viewing_repository,
+            brawler_repository,
+            achievement_repository,
+            notification_repository,
+            config,
+        }
+    }
+
+    pub async fn invite(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        invite_brawler_model: InviteBrawlerModel,
+    ) -> Result<i32> {
+        let expires_in_hours = invite_brawler_model
+            .expires_in_hours
+            .unwrap_or(DEFAULT_INVITATION_HOURS);
+        if !(1..=MAX_INVITATION_HOURS).contains(&expires_in_hours) {
+            return Err(anyhow::anyhow!(
+                "expires_in_hours must be between 1 and {}",
+                MAX_INVITATION_HOURS
+            ));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can invite brawlers"));
+        }
+        if mission.status != MissionStatuses::Open && mission.status != MissionStatuses::Failed {
+            return Err(anyhow::anyhow!("Mission is not joinable"));
+        }
+
+        let invitee = self
+            .brawler_repository
+            .find_by_username(invite_brawler_model.username.trim())
+            .await
+            .map_err(|_| anyhow::anyhow!("Brawler not found"))?;
+        if invitee.id == chief_id {
+            return Err(anyhow::anyhow!("Chiefs cannot invite themselves"));
+        }
+        if self
+            .crew_operation_repository
+            .is_banned(mission_id, invitee.id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("Brawler was removed from this mission and cannot rejoin it"));
+        }
+        let crew_ids = self.mission_viewing_repository.get_crew_ids(mission_id).await?;
+        if crew_ids.contains(&invitee.id) {
+            return Err(anyhow::anyhow!("Brawler is already in this mission's crew"));
+        }
+
+        // Clears lapsed invitations so the brawler can be invited again
+        self.mission_invitation_repository.expire_lapsed(Utc::now().naive_utc()).await?;
+        let pending = self
+            .mission_invitation_repository
+            .get_by_mission(mission_id, InvitationStatuses::Pending)
+            .await?;
+        if pending.iter().any(|invitation| invitation.brawler_id == invitee.id) {
+            return Err(anyhow::anyhow!("Brawler already has a pending invitation to this mission"));
+        }
+
+        let invitation_id = self
+            .mission_invitation_repository
+            .add(AddMissionInvitationEntity {
+                mission_id,
+                brawler_id: invitee.id,
+                invited_by: chief_id,
+                status: InvitationStatuses::Pending,
+                expires_at: Utc::now().naive_utc() + Duration::hours(expires_in_hours),
+            })
+            .await?;
+
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[invitee.id],
+                NotificationKinds::MissionInvitation,
+                Some(mission_id),
+                &format!("You were invited to join mission \"{}\"", mission.name),
+            )
+            .await
+        {
+            error!("Invitation ({}) notification failed: {}", invitation_id, e);
+        }
+
+        Ok(invitation_id)
+    }
+
+    /// The brawler's invitations, pending ones unless another status is asked for.
+    pub async fn get_mine(
+        &self,
+        brawler_id: i32,
+        status: Option<InvitationStatuses>,
+    ) -> Result<Vec<MissionInvitationModel>> {
+        self.mission_invitation_repository.expire_lapsed(Utc::now().naive_utc()).await?;
+
+        self.mission_invitation_repository
+            .get_by_brawler(brawler_id, status.unwrap_or(InvitationStatuses::Pending))
+            .await
+    }
+
+    pub async fn get_for_mission(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        status: Option<InvitationStatuses>,
+    ) -> Result<Vec<MissionInvitationModel>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can view a mission's invitations"));
+        }
+        self.mission_invitation_repository.expire_lapsed(Utc::now().naive_utc()).await?;
+
+        self.mission_invitation_repository
+            .get_by_mission(mission_id, status.unwrap_or(InvitationStatuses::Pending))
+            .await
+    }
+
+    /// Joins the invited brawler's crew directly, skipping the approval queue but not the
+    /// daily limit, rank requirement, capacity or status checks.
+    pub async fn accept(&self, invitation_id: i32, brawler_id: i32) -> Result<()> {
+        let invitation = self.mission_invitation_repository.get_one(invitation_id).await?;
+        // Someone else's invitation is reported as missing rather than forbidden
+        if invitation.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("Invitation not found"));
+        }
+        if invitation.status != InvitationStatuses::Pending {
+            return Err(anyhow::anyhow!("Invitation is no longer pending"));
+        }
+        if invitation.expires_at <= Utc::now().naive_utc() {
+            self.mission_invitation_repository.expire_lapsed(Utc::now().naive_utc()).await?;
+            return Err(anyhow::anyhow!("Invitation has expired"));
+        }
+
+        let daily_count = self.mission_viewing_repository.get_daily_interaction_count(brawler_id).await?;
+        if daily_count >= 3 {
+             return Err(anyhow::anyhow!("Daily mission limit (3) reached. You cannot create or join more missions today."));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(invitation.mission_id).await?;
+        if mission.status != MissionStatuses::Open && mission.status != MissionStatuses::Failed {
+            return Err(anyhow::anyhow!("Mission is not joinable"));
+        }
+
+        // An invitation skips the approval queue, not the rank requirement
+        let rank_requirement = mission.rank_requirement();
+        if rank_requirement != RankRequirementModel::default() {
+            let total_points = self.mission_viewing_repository.get_total_points(brawler_id).await?;
+            rank_requirement.ensure_met(total_points, &self.config.tier.thresholds)?;
+        }
+
+        // Capacity, status and the invitation itself are re-checked by the repository under
+        // row locks, and the join and the accept commit together
+        self.mission_invitation_repository
+            .accept(invitation_id, mission.crew_capacity(self.config.max_crew_per_mission))
+            .await?;
+
+        // The brawler is in the crew now; the rest is best-effort
+        if let Err(e) = self.withdraw_join_requests(mission.id, brawler_id).await {
+            error!(
+                "Withdrawing join requests for mission ({}) after an accepted invitation failed: {}",
+                mission.id, e
+            );
+        }
+
+        if let Err(e) = AchievementUseCase::new(Arc::clone(&self.achievement_repository))
+            .evaluate(brawler_id)
+            .await
+        {
+            error!("Achievement evaluation for brawler ({}) failed: {}", brawler_id, e);
+        }
+
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[invitation.invited_by],
+                NotificationKinds::InvitationAccepted,
+                Some(mission.id),
+                &format!("Your invitation to mission \"{}\" was accepted", mission.name),
+            )
+            .await
+        {
+            error!("Invitation ({}) accepted notification failed: {}", invitation_id, e);
+        }
+
+        Ok(())
+    }
+
+    /// A join request for the same mission is moot once the brawler is in the crew.
+    async fn withdraw_join_requests(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+        for request in self
+            .crew_operation_repository
+            .get_join_requests_by_brawler(brawler_id, JoinRequestStatuses::Pending)
+            .await?
+            .into_iter()
+            .filter(|request| request.mission_id == mission_id)
+        {
+            self.crew_operation_repository
+                .close_join_request(request.id, JoinRequestStatuses::Withdrawn)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn decline(&self, invitation_id: i32, brawler_id: i32) -> Result<()> {
+        let invitation = self.mission_invitation_repository.get_one(invitation_id).await?;
+        if invitation.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("Invitation not found"));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(invitation.mission_id).await?;
+        self.mission_invitation_repository
+            .close(invitation_id, InvitationStatuses::Declined)
+            .await?;
+
+        if let Err(e) = NotificationUseCase::new(Arc::clone(&self.notification_repository))
+            .notify(
+                &[invitation.invited_by],
+                NotificationKinds::InvitationDeclined,
+                Some(mission.id),
+                &format!("Your invitation to mission \"{}\" was declined", mission.name),
+            )
+            .await
+        {
+            error!("Invitation ({}) declined notification failed: {}", invitation_id, e);
+        }
+
+        Ok(())
+    }
+}
@@ -1,46 +1,79 @@
 use std::sync::Arc;
 
-use crate::domain::{
-    repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
-        brawlers::BrawlerRepository,
-    },
-    value_objects::{
-        mission_model::{AddMissionModel, EditMissionModel},
-        mission_statuses::MissionStatuses,
+use chrono::{Duration, Utc};
+use tracing::error;
+
+use crate::{
+    application::use_cases::crew_operation::CrewOperationUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
+            mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::{
+            mission_lane_model::validate_lanes,
+            mission_model::{AddMissionModel, EditMissionModel, normalize_difficulty},
+            mission_tag_model::normalize_tags,
+            rank_requirement_model::RankRequirementModel,
+        },
     },
 };
 
-pub struct MissionManagementUseCase<T1, T2, T3>
+pub struct MissionManagementUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
-    T3: BrawlerRepository + Send + Sync,
+    T3: CrewOperationRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
-    brawler_repository: Arc<T3>,
+    crew_operation_repository: Arc<T3>,
+    achievement_repository: Arc<T4>,
+    notification_repository: Arc<T5>,
+    config: Arc<DotEnvyConfig>,
 }
 
 use anyhow::Result;
-impl<T1, T2, T3> MissionManagementUseCase<T1, T2, T3>
+impl<T1, T2, T3, T4, T5> MissionManagementUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
-    T3: BrawlerRepository + Send + Sync,
+    T3: CrewOperationRepository + Send + Sync + 'static,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
-        brawler_repository: Arc<T3>,
+        crew_operation_repository: Arc<T3>,
+        achievement_repository: Arc<T4>,
+        notification_repository: Arc<T5>,
+        config: Arc<DotEnvyConfig>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
-            brawler_repository,
+            crew_operation_repository,
+            achievement_repository,
+            notification_repository,
+            config,
         }
     }
 
+    fn validate_max_crew(&self, max_crew: i32) -> Result<()> {
+        if !(1..=self.config.max_crew_per_mission).contains(&max_crew) {
+            return Err(anyhow::anyhow!(
+                "max_crew must be between 1 and {}",
+                self.config.max_crew_per_mission
+            ));
+        }
+        Ok(())
+    }
+
     pub async fn add(&self, chief_id: i32, mut add_mission_model: AddMissionModel) -> Result<i32> {
         // Daily Limit Check
         let daily_count = self.mission_viewing_repository.get_daily_interaction_count(chief_id).await?;
@@ -53,6 +86,15 @@ where
                 "Mission name must be at least 3 characters long."
             ));
         }
+        self.validate_max_crew(add_mission_model.max_crew)?;
+        add_mission_model.difficulty = normalize_difficulty(&add_mission_model.difficulty)?;
+        let tags = normalize_tags(add_mission_model.tags.as_deref().unwrap_or_default())?;
+        let lanes = validate_lanes(
+            add_mission_model.lanes.as_deref().unwrap_or_default(),
+            add_mission_model.max_crew,
+        )?;
+        add_mission_model.rank_requirement.validate()?;
+
         add_mission_model.description = add_mission_model.description.and_then(|s| {
             if s.trim().is_empty() {
                 None
@@ -65,7 +107,7 @@ where
 
         let result = self
             .mission_management_repository
-            .add(insert_mission_entity)
+            .add(insert_mission_entity, tags, lanes)
             .await?;
 
         Ok(result)
@@ -77,6 +119,10 @@ where
         chief_id: i32,
         mut edit_mission_model: EditMissionModel,
     ) -> Result<i32> {
+        let old_mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if old_mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can edit this mission"));
+        }
 
         if let Some(mission_name) = &edit_mission_model.name {
             if mission_name.trim().is_empty() {
@@ -90,6 +136,20 @@ where
             }
         }
 
+        edit_mission_model.difficulty = edit_mission_model
+            .difficulty
+            .as_deref()
+            .map(normalize_difficulty)
+            .transpose()?;
+        if let Some(max_crew) = edit_mission_model.max_crew {
+            self.validate_max_crew(max_crew)?;
+        }
+        let tags = edit_mission_model
+            .tags
+            .as_deref()
+            .map(normalize_tags)
+            .transpose()?;
+
         edit_mission_model.description = edit_mission_model.description.and_then(|s| {
             if s.trim().is_empty() {
                 None
@@ -104,42 +164,112 @@ where
             .crew_counting(mission_id)
             .await?;
         if crew_count > 0 {
+            // Once crew has joined only the crew size can still change, so waitlisted
+            // brawlers can be let in
+            let only_max_crew = edit_mission_model.name.is_none()
+                && edit_mission_model.description.is_none()
+                && edit_mission_model.difficulty.is_none()
+                && edit_mission_model.due_date.is_none()
+                && edit_mission_model.tags.is_none()
+                && edit_mission_model.requires_approval.is_none()
+                && edit_mission_model.lanes.is_none()
+                && edit_mission_model.rank_requirement == RankRequirementModel::default()
+                && !edit_mission_model.clear_rank_requirement.unwrap_or(false);
+            match edit_mission_model.max_crew {
+                Some(max_crew) if only_max_crew => {
+                    if (max_crew as i64) < crew_count {
+                        return Err(anyhow::anyhow!(
+                            "max_crew cannot be lower than the current crew size ({})",
+                            crew_count
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Mission has been taken by brawler for now!"
+                    ));
+                }
+            }
+        }
+
+        if let Some(status) = edit_mission_model.status
+            && status != old_mission.status
+        {
             return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
+                "Mission status cannot be edited directly. Use mission operations to move it from {}",
+                old_mission.status
             ));
         }
 
-        let old_mission = self.mission_viewing_repository.get_one(mission_id).await?;
-        let new_status = edit_mission_model.status.clone();
+        // Bounds left out keep their current value unless cleared, so check them together
+        let old_rank_requirement = if edit_mission_model.clear_rank_requirement.unwrap_or(false) {
+            RankRequirementModel::default()
+        } else {
+            old_mission.rank_requirement()
+        };
+        RankRequirementModel {
+            min_rank: edit_mission_model.rank_requirement.min_rank.or(old_rank_requirement.min_rank),
+            max_rank: edit_mission_model.rank_requirement.max_rank.or(old_rank_requirement.max_rank),
+            min_total_points: edit_mission_model
+                .rank_requirement
+                .min_total_points
+                .or(old_rank_requirement.min_total_points),
+        }
+        .validate()?;
 
-        let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        let max_crew = edit_mission_model.max_crew.unwrap_or(old_mission.max_crew);
+        let lanes = match edit_mission_model.lanes.as_deref() {
+            Some(lanes) => Some(validate_lanes(lanes, max_crew)?),
+            None => {
+                let total_slots: i64 = self
+                    .mission_viewing_repository
+                    .get_lanes(&[mission_id])
+                    .await?
+                    .iter()
+                    .map(|lane| lane.slots as i64)
+                    .sum();
+                if total_slots > max_crew as i64 {
+                    return Err(anyhow::anyhow!(
+                        "Lane slots ({}) cannot exceed max_crew ({})",
+                        total_slots,
+                        max_crew
+                    ));
+                }
+                None
+            }
+        };
+
+        let edit_mission_entity = edit_mission_model.to_entity();
 
         let result = self
             .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
+            .edit(mission_id, chief_id, edit_mission_entity, tags, lanes)
             .await?;
 
-        // 🌟 Award points if changed to Completed 🌟
-        if new_status == Some(MissionStatuses::Completed.to_string()) && old_mission.status != MissionStatuses::Completed.to_string() {
-            let mut member_ids = self.mission_viewing_repository.get_crew_ids(mission_id).await?;
-            member_ids.push(chief_id);
-            
-            let mission_points = old_mission.base_points as i64;
-            for uid in member_ids {
-                 let earned_today = self.mission_viewing_repository.get_daily_earned_points(uid).await?;
-                 let prior_points = if earned_today > mission_points { earned_today - mission_points } else { 0 };
-                 let limit = 15;
-                 let allowed = if prior_points < limit { limit - prior_points } else { 0 };
-                 let to_add = if mission_points < allowed { mission_points } else { allowed };
-                 if to_add > 0 {
-                     self.brawler_repository.add_points(uid, to_add as i32).await?;
-                 }
-            }
+        // Slots a bigger crew opens go to the waitlist first; the edit itself already succeeded
+        if max_crew > old_mission.max_crew
+            && let Err(e) = self.promote_waitlisted(mission_id).await
+        {
+            error!("Waitlist promotion for mission ({}) failed: {}", mission_id, e);
         }
 
         Ok(result)
     }
 
+    async fn promote_waitlisted(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        CrewOperationUseCase::new(
+            Arc::clone(&self.crew_operation_repository),
+            Arc::clone(&self.mission_viewing_repository),
+            Arc::clone(&self.achievement_repository),
+            Arc::clone(&self.notification_repository),
+            Arc::clone(&self.config),
+        )
+        .promote_waitlisted(&mission)
+        .await
+    }
+
     pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
         let crew_count = self
             .mission_viewing_repository
@@ -156,4 +286,159 @@ where
             .await?;
         Ok(())
     }
+
+    /// Hides missions that finished longer ago than MISSION_ARCHIVE_AFTER_HOURS from default listings.
+    pub async fn archive_finished(&self) -> Result<usize> {
+        let finished_before =
+            Utc::now().naive_utc() - Duration::hours(self.config.archive.archive_after_hours);
+
+        self.mission_management_repository
+            .archive_finished(finished_before)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::domain::{
+        entities::missions::MissionEntity,
+        repositories::{
+            achievements::MockAchievementRepository,
+            crew_operation::MockCrewOperationRepository,
+            mission_management::MockMissionManagementRepository,
+            mission_viewing::MockMissionViewingRepository,
+            notifications::MockNotificationRepository,
+        },
+        value_objects::{
+            lanes::Lanes, mission_lane_model::LaneSlotModel, mission_statuses::MissionStatuses,
+        },
+    };
+
+    fn mission(chief_id: i32) -> MissionEntity {
+        MissionEntity {
+            id: 1,
+            name: "Push mid".to_string(),
+            description: None,
+            status: MissionStatuses::Open,
+            chief_id,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            deleted_at: None,
+            max_crew: 3,
+            difficulty: "NORMAL".to_string(),
+            base_points: 3,
+            due_date: None,
+            archived_at: None,
+            series_id: None,
+            requires_approval: false,
+            min_rank: None,
+            max_rank: None,
+            min_total_points: None,
+            finished_at: None,
+        }
+    }
+
+    fn edit_model() -> EditMissionModel {
+        EditMissionModel {
+            name: None,
+            description: None,
+            status: None,
+            max_crew: None,
+            difficulty: None,
+            due_date: None,
+            tags: None,
+            requires_approval: None,
+            lanes: None,
+            rank_requirement: RankRequirementModel::default(),
+            clear_rank_requirement: None,
+        }
+    }
+
+    // Any call past the chief check would hit a mock without expectations and panic
+    fn use_case(
+        chief_id: i32,
+    ) -> MissionManagementUseCase<
+        MockMissionManagementRepository,
+        MockMissionViewingRepository,
+        MockCrewOperationRepository,
+        MockAchievementRepository,
+        MockNotificationRepository,
+    > {
+        let mut mission_viewing_repository = MockMissionViewingRepository::new();
+        mission_viewing_repository
+            .expect_get_one()
+            .returning(move |_| Ok(mission(chief_id)));
+
+        MissionManagementUseCase::new(
+            Arc::new(MockMissionManagementRepository::new()),
+            Arc::new(mission_viewing_repository),
+            Arc::new(MockCrewOperationRepository::new()),
+            Arc::new(MockAchievementRepository::new()),
+            Arc::new(MockNotificationRepository::new()),
+            Arc::new(DotEnvyConfig::for_tests()),
+        )
+    }
+
+    #[tokio::test]
+    async fn edit_rejects_a_max_crew_change_from_a_non_chief() {
+        let result = use_case(1)
+            .edit(
+                1,
+                2,
+                EditMissionModel {
+                    max_crew: Some(5),
+                    ..edit_model()
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the chief can edit this mission"
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_rejects_a_lane_change_from_a_non_chief() {
+        let result = use_case(1)
+            .edit(
+                1,
+                2,
+                EditMissionModel {
+                    lanes: Some(vec![LaneSlotModel {
+                        lane: Lanes::Mid,
+                        slots: 1,
+                    }]),
+                    ..edit_model()
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the chief can edit this mission"
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_rejects_a_rank_requirement_change_from_a_non_chief() {
+        let result = use_case(1)
+            .edit(
+                1,
+                2,
+                EditMissionModel {
+                    clear_rank_requirement: Some(true),
+                    ..edit_model()
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the chief can edit this mission"
+        );
+    }
 }
@@ -1,116 +1,109 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Duration, Utc};
+use tracing::{error, info, warn};
 
-use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
-        brawlers::BrawlerRepository,
+use crate::{
+    application::use_cases::{
+        achievements::AchievementUseCase, notifications::NotificationUseCase, progression::{DAILY_POINT_LIMIT, ProgressionUseCase},
+        streaks::StreakUseCase,
+    },
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::missions::MissionEntity,
+        repositories::{
+            achievements::AchievementRepository, brawlers::BrawlerRepository,
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository,
+            notifications::NotificationRepository,
+        },
+        value_objects::{
+            mission_statuses::MissionStatuses, mission_transition::MissionTransitionContext,
+            notification_kinds::NotificationKinds,
+            point_reasons::PointReasons,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 
-pub struct MissionOperationUseCase<T1, T2, T3>
+pub struct MissionOperationUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     mission_operation_repository: Arc<T1>,
     missiom_viewing_repository: Arc<T2>,
     brawler_repository: Arc<T3>,
+    achievement_repository: Arc<T4>,
+    notification_repository: Arc<T5>,
+    config: Arc<DotEnvyConfig>,
 }
 
-impl<T1, T2, T3> MissionOperationUseCase<T1, T2, T3>
+impl<T1, T2, T3, T4, T5> MissionOperationUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     pub fn new(
         mission_operation_repository: Arc<T1>, 
         missiom_viewing_repository: Arc<T2>,
         brawler_repository: Arc<T3>,
+        achievement_repository: Arc<T4>,
+        notification_repository: Arc<T5>,
+        config: Arc<DotEnvyConfig>,
     ) -> Self {
         Self {
             mission_operation_repository,
             missiom_viewing_repository,
             brawler_repository,
+            achievement_repository,
+            notification_repository,
+            config,
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
-
+    /// Runs the state machine guards and records the change with its history row.
+    async fn transition(
+        &self,
+        mission: &MissionEntity,
+        to: MissionStatuses,
+        actor_id: Option<i32>,
+    ) -> Result<i32> {
         let crew_count = self
             .missiom_viewing_repository
-            .crew_counting(mission_id)
+            .crew_counting(mission.id)
             .await?;
 
-        let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
-
-        let update_condition = is_status_open_or_fail
-            && crew_count > 0
-            && crew_count < max_crew_per_mission
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            if !is_status_open_or_fail {
-                return Err(anyhow::anyhow!(
-                    "Mission status must be Open or Failed to start. Current: {}",
-                    mission.status
-                ));
-            }
-            if crew_count <= 0 {
-                return Err(anyhow::anyhow!(
-                    "Mission must have at least one crew member"
-                ));
-            }
-            if crew_count >= max_crew_per_mission {
-                return Err(anyhow::anyhow!(
-                    "Mission crew limit reached or exceeded (Max: {})",
-                    max_crew_per_mission
-                ));
-            }
-            if mission.chief_id != chief_id {
-                return Err(anyhow::anyhow!("Only the Chief can start the mission"));
-            }
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+        let context = MissionTransitionContext {
+            chief_id: mission.chief_id,
+            crew_count,
+            crew_capacity: mission.crew_capacity(self.config.max_crew_per_mission) as i64,
+        };
+        let transition = mission.status.transition_to(to, actor_id, &context)?;
 
-        let result = self
-            .mission_operation_repository
-            .to_progress(mission_id, chief_id)
-            .await?;
-        Ok(result)
+        self.mission_operation_repository
+            .transition(mission.id, transition)
+            .await
     }
 
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
+        self.transition(&mission, MissionStatuses::InProgress, Some(chief_id))
+            .await
+    }
 
-        if !update_condition {
-            if mission.status != MissionStatuses::InProgress.to_string() {
-                return Err(anyhow::anyhow!(
-                    "Mission must be In Progress to complete. Current: {}",
-                    mission.status
-                ));
-            }
-            if mission.chief_id != chief_id {
-                return Err(anyhow::anyhow!("Only the Chief can complete the mission"));
-            }
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
         let result = self
-            .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+            .transition(&mission, MissionStatuses::Completed, Some(chief_id))
             .await?;
 
         // 🌟 Add Points Logic 🌟
@@ -119,6 +112,13 @@ where
         member_ids.push(chief_id); // Add Chief
         
         let mission_points = mission.base_points as i64;
+        let achievement_use_case = AchievementUseCase::new(Arc::clone(&self.achievement_repository));
+        let streak_use_case =
+            StreakUseCase::new(Arc::clone(&self.brawler_repository), Arc::clone(&self.config));
+        let progression_use_case =
+            ProgressionUseCase::new(Arc::clone(&self.brawler_repository), Arc::clone(&self.config));
+        let scoring_env = &self.config.scoring;
+        let completed_at = Utc::now().naive_utc();
 
         for uid in member_ids {
              // Because we updated status already, daily_earned INCLUDES this mission points
@@ -130,7 +130,7 @@ where
              // Points prior to this mission (approx logic)
              let prior_points = if earned_today > mission_points { earned_today - mission_points } else { 0 };
              
-             let limit = 15;
+             let limit = DAILY_POINT_LIMIT;
              let allowed = if prior_points < limit { limit - prior_points } else { 0 };
              
              let to_add = if mission_points < allowed { mission_points } else { allowed };
@@ -139,7 +139,25 @@ where
                  // add_points accumulates Total Points.
                  // Note: We are using "daily limit logic" to decide IF to add to Total.
                  // We don't track daily points in DB, we calculate them.
-                 self.brawler_repository.add_points(uid, to_add as i32).await?;
+                 progression_use_case
+                     .award_points(uid, to_add as i32, PointReasons::MissionCompleted, Some(mission_id))
+                     .await?;
+
+                 if let Some((reason, delta)) =
+                     scoring_env.completion_adjustment(mission.due_date, completed_at, to_add as i32)
+                 {
+                     progression_use_case
+                         .award_points(uid, delta, reason, Some(mission_id))
+                         .await?;
+                 }
+             }
+
+             // The mission is completed either way; streaks and achievements are best-effort
+             if let Err(e) = streak_use_case.record_activity(uid).await {
+                 error!("Streak update for brawler ({}) failed: {}", uid, e);
+             }
+             if let Err(e) = achievement_use_case.evaluate(uid).await {
+                 error!("Achievement evaluation for brawler ({}) failed: {}", uid, e);
              }
         }
 
@@ -149,25 +167,189 @@ where
     pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            if mission.status != MissionStatuses::InProgress.to_string() {
-                return Err(anyhow::anyhow!(
-                    "Mission must be In Progress to fail. Current: {}",
-                    mission.status
-                ));
+        self.fail(&mission, Some(chief_id)).await
+    }
+
+    pub async fn cancel(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
+
+        self.cancel_mission(&mission, Some(chief_id)).await
+    }
+
+    /// Sends pre-deadline reminders and fails (or cancels, when nobody joined) overdue missions.
+    pub async fn enforce_due_dates(&self) -> Result<()> {
+        let due_date_env = &self.config.due_date;
+        let now = Utc::now().naive_utc();
+        let notification_use_case = NotificationUseCase::new(Arc::clone(&self.notification_repository));
+
+        for mission in self
+            .mission_operation_repository
+            .claim_due_reminders(now, now + Duration::minutes(due_date_env.reminder_minutes))
+            .await?
+        {
+            let Some(due_date) = mission.due_date else {
+                continue;
+            };
+            let sent = match self.missiom_viewing_repository.get_crew_ids(mission.id).await {
+                Ok(mut member_ids) => {
+                    member_ids.push(mission.chief_id);
+                    notification_use_case
+                        .notify(
+                            &member_ids,
+                            NotificationKinds::DueDateReminder,
+                            Some(mission.id),
+                            &format!(
+                                "Mission \"{}\" is due at {} UTC",
+                                mission.name,
+                                due_date.format("%Y-%m-%d %H:%M")
+                            ),
+                        )
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = sent {
+                error!("Mission ({}) due-date reminder failed: {}", mission.id, e);
+                if let Err(e) = self
+                    .mission_operation_repository
+                    .release_due_reminder(mission.id)
+                    .await
+                {
+                    error!("Mission ({}) due-date reminder could not be released: {}", mission.id, e);
+                }
             }
-            if mission.chief_id != chief_id {
-                return Err(anyhow::anyhow!("Only the Chief can fail the mission"));
+        }
+
+        for mission in self
+            .missiom_viewing_repository
+            .get_overdue(now - Duration::minutes(due_date_env.grace_minutes))
+            .await?
+        {
+            let mut member_ids = match self.missiom_viewing_repository.get_crew_ids(mission.id).await {
+                Ok(member_ids) => member_ids,
+                Err(e) => {
+                    warn!("Mission ({}) due-date enforcement skipped: {}", mission.id, e);
+                    continue;
+                }
+            };
+            let result = if member_ids.is_empty() {
+                self.cancel_mission(&mission, None).await
+            } else {
+                self.fail(&mission, None).await
+            };
+
+            // One mission changing under us (e.g. completed meanwhile) shouldn't stop the sweep
+            let to_status = match result {
+                Ok(_) if member_ids.is_empty() => MissionStatuses::Cancelled,
+                Ok(_) => MissionStatuses::Failed,
+                Err(e) => {
+                    warn!("Mission ({}) due-date enforcement skipped: {}", mission.id, e);
+                    continue;
+                }
+            };
+            info!("Mission ({}) passed its due date and is now {}", mission.id, to_status);
+
+            member_ids.push(mission.chief_id);
+            if let Err(e) = notification_use_case
+                .notify(
+                    &member_ids,
+                    NotificationKinds::MissionExpired,
+                    Some(mission.id),
+                    &format!(
+                        "Mission \"{}\" passed its due date and was marked {}",
+                        mission.name, to_status
+                    ),
+                )
+                .await
+            {
+                error!("Mission ({}) expiry notification failed: {}", mission.id, e);
             }
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
         }
+
+        Ok(())
+    }
+
+    async fn fail(&self, mission: &MissionEntity, actor_id: Option<i32>) -> Result<i32> {
+        let mission_id = mission.id;
         let result = self
-            .mission_operation_repository
-            .to_failed(mission_id, chief_id)
+            .transition(mission, MissionStatuses::Failed, actor_id)
+            .await?;
+
+        // The mission has failed either way; a missed penalty is logged rather than reported
+        if let Err(e) = self.apply_failure_penalties(mission).await {
+            error!("Mission ({}) failure penalties were not applied: {}", mission_id, e);
+        }
+
+        Ok(result)
+    }
+
+    /// Deducts the failure penalties once per brawler and mission, so a mission failed again
+    /// after being reopened doesn't charge the same brawlers twice.
+    async fn apply_failure_penalties(&self, mission: &MissionEntity) -> Result<()> {
+        let mission_id = mission.id;
+        let scoring_env = &self.config.scoring;
+        let progression_use_case =
+            ProgressionUseCase::new(Arc::clone(&self.brawler_repository), Arc::clone(&self.config));
+
+        let mut penalties = Vec::new();
+        if scoring_env.failed_chief_penalty > 0 {
+            penalties.push((mission.chief_id, scoring_env.failed_chief_penalty));
+        }
+        if scoring_env.failed_crew_penalty > 0 {
+            for uid in self.missiom_viewing_repository.get_crew_ids(mission_id).await? {
+                penalties.push((uid, scoring_env.failed_crew_penalty));
+            }
+        }
+
+        for (brawler_id, penalty) in penalties {
+            if self
+                .brawler_repository
+                .has_point_entry(brawler_id, mission_id, PointReasons::MissionFailedPenalty)
+                .await?
+            {
+                continue;
+            }
+            progression_use_case
+                .deduct_points(
+                    brawler_id,
+                    penalty,
+                    PointReasons::MissionFailedPenalty,
+                    Some(mission_id),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn cancel_mission(&self, mission: &MissionEntity, actor_id: Option<i32>) -> Result<i32> {
+        let result = self
+            .transition(mission, MissionStatuses::Cancelled, actor_id)
             .await?;
 
+        let message = match actor_id {
+            Some(_) => format!("Mission \"{}\" was cancelled by its chief", mission.name),
+            None => format!("Mission \"{}\" was cancelled", mission.name),
+        };
+        // The mission is cancelled either way; telling the crew is best-effort
+        let notified = match self.missiom_viewing_repository.get_crew_ids(mission.id).await {
+            Ok(crew_ids) => {
+                NotificationUseCase::new(Arc::clone(&self.notification_repository))
+                    .notify(
+                        &crew_ids,
+                        NotificationKinds::MissionCancelled,
+                        Some(mission.id),
+                        &message,
+                    )
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = notified {
+            error!("Mission ({}) cancellation notification failed: {}", mission.id, e);
+        }
+
         Ok(result)
     }
 }
@@ -0,0 +1,398 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use tracing::{error, info};
+
+use crate::{
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::mission_series::{
+            AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity,
+        },
+        repositories::mission_series::MissionSeriesRepository,
+        value_objects::{
+            mission_model::normalize_difficulty,
+            mission_series_model::{AddMissionSeriesModel, EditMissionSeriesModel},
+            recurrence_rule::RecurrenceRule,
+        },
+    },
+};
+
+pub struct MissionSeriesUseCase<T>
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    mission_series_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T> MissionSeriesUseCase<T>
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    pub fn new(mission_series_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
+        Self {
+            mission_series_repository,
+            config,
+        }
+    }
+
+    /// Returns the normalized difficulty to store.
+    fn validate_template(&self, name: &str, max_crew: i32, difficulty: &str) -> Result<String> {
+        if name.trim().len() < 3 {
+            return Err(anyhow::anyhow!(
+                "Mission name must be at least 3 characters long."
+            ));
+        }
+        let difficulty = normalize_difficulty(difficulty)?;
+        if !(1..=self.config.max_crew_per_mission).contains(&max_crew) {
+            return Err(anyhow::anyhow!(
+                "max_crew must be between 1 and {}",
+                self.config.max_crew_per_mission
+            ));
+        }
+        Ok(difficulty)
+    }
+
+    fn normalize_description(description: Option<String>) -> Option<String> {
+        description.and_then(|s| {
+            if s.trim().is_empty() {
+                None
+            } else {
+                Some(s.trim().to_string())
+            }
+        })
+    }
+
+    /// The edited value if one was sent, else nothing when `clear` is set, else the stored one.
+    fn merge<V>(edited: Option<V>, clear: Option<bool>, stored: Option<V>) -> Option<V> {
+        if clear.unwrap_or(false) {
+            edited
+        } else {
+            edited.or(stored)
+        }
+    }
+
+    pub async fn add(&self, chief_id: i32, add_model: AddMissionSeriesModel) -> Result<i32> {
+        let difficulty =
+            self.validate_template(&add_model.name, add_model.max_crew, &add_model.difficulty)?;
+
+        let rule = RecurrenceRule {
+            frequency: add_model.frequency,
+            weekdays: add_model.weekdays,
+            end_date: add_model.end_date,
+            max_occurrences: add_model.max_occurrences,
+        };
+        rule.validate()?;
+
+        let today = Utc::now().date_naive();
+        let start_date = add_model.start_date.unwrap_or(today);
+        if start_date < today {
+            return Err(anyhow::anyhow!("start_date cannot be in the past"));
+        }
+        let next_occurrence_on = rule
+            .next_on_or_after(start_date, 0)
+            .ok_or_else(|| anyhow::anyhow!("Recurrence rule does not produce any occurrence"))?;
+
+        self.mission_series_repository
+            .add(AddMissionSeriesEntity {
+                chief_id,
+                name: add_model.name.trim().to_string(),
+                description: Self::normalize_description(add_model.description),
+                max_crew: add_model.max_crew,
+                difficulty,
+                due_time: add_model.due_time,
+                frequency: rule.frequency,
+                weekdays: rule.weekday_names(),
+                start_date,
+                end_date: rule.end_date,
+                max_occurrences: rule.max_occurrences,
+                next_occurrence_on: Some(next_occurrence_on),
+            })
+            .await
+    }
+
+    pub async fn get_mine(&self, chief_id: i32) -> Result<Vec<MissionSeriesEntity>> {
+        self.mission_series_repository.get_by_chief(chief_id).await
+    }
+
+    async fn get_owned(&self, series_id: i32, chief_id: i32) -> Result<MissionSeriesEntity> {
+        let series = self.mission_series_repository.get_one(series_id).await?;
+        if series.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the Chief can manage this mission series"));
+        }
+        Ok(series)
+    }
+
+    /// Changes the template and rule for occurrences that don't exist yet.
+    /// Missions already created from the series keep their own values.
+    pub async fn edit(
+        &self,
+        series_id: i32,
+        chief_id: i32,
+        edit_model: EditMissionSeriesModel,
+    ) -> Result<i32> {
+        let series = self.get_owned(series_id, chief_id).await?;
+        if !series.is_active {
+            return Err(anyhow::anyhow!("Mission series has already ended"));
+        }
+
+        let name = edit_model.name.unwrap_or(series.name.clone());
+        let max_crew = edit_model.max_crew.unwrap_or(series.max_crew);
+        let difficulty = self.validate_template(
+            &name,
+            max_crew,
+            edit_model.difficulty.as_deref().unwrap_or(&series.difficulty),
+        )?;
+
+        let current_rule = series.rule()?;
+        let rule = RecurrenceRule {
+            frequency: edit_model.frequency.unwrap_or(current_rule.frequency),
+            weekdays: edit_model.weekdays.unwrap_or(current_rule.weekdays),
+            end_date: Self::merge(
+                edit_model.end_date,
+                edit_model.clear_end_date,
+                current_rule.end_date,
+            ),
+            max_occurrences: Self::merge(
+                edit_model.max_occurrences,
+                edit_model.clear_max_occurrences,
+                current_rule.max_occurrences,
+            ),
+        };
+        rule.validate()?;
+
+        // Never reschedule onto a day that already has its occurrence
+        let today = Utc::now().date_naive();
+        let from = [
+            Some(today),
+            Some(series.start_date),
+            series.last_occurrence_on.and_then(|last| last.succ_opt()),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(today);
+        let next_occurrence_on = rule
+            .next_on_or_after(from, series.occurrences_created)
+            .ok_or_else(|| anyhow::anyhow!("Recurrence rule has no occurrences left"))?;
+
+        self.mission_series_repository
+            .edit(
+                series_id,
+                EditMissionSeriesEntity {
+                    name: name.trim().to_string(),
+                    description: Self::normalize_description(Self::merge(
+                        edit_model.description,
+                        edit_model.clear_description,
+                        series.description,
+                    )),
+                    max_crew,
+                    difficulty,
+                    due_time: Self::merge(
+                        edit_model.due_time,
+                        edit_model.clear_due_time,
+                        series.due_time,
+                    ),
+                    frequency: rule.frequency,
+                    weekdays: rule.weekday_names(),
+                    end_date: rule.end_date,
+                    max_occurrences: rule.max_occurrences,
+                    next_occurrence_on: Some(next_occurrence_on),
+                    is_active: true,
+                },
+            )
+            .await
+    }
+
+    /// Stops future occurrences; missions already created are left alone.
+    pub async fn stop(&self, series_id: i32, chief_id: i32) -> Result<()> {
+        self.get_owned(series_id, chief_id).await?;
+        self.mission_series_repository.stop(series_id).await
+    }
+
+    /// Creates today's occurrence for every series that is due.
+    pub async fn create_due_occurrences(&self) -> Result<usize> {
+        let today = Utc::now().date_naive();
+        let mut created = 0;
+
+        // One broken series shouldn't hold up the others
+        for series in self.mission_series_repository.get_due(today).await? {
+            match self.create_due_occurrence(&series, today).await {
+                Ok(Some(mission_id)) => {
+                    created += 1;
+                    info!(
+                        "Mission series ({}) created mission ({}) for {}",
+                        series.id, mission_id, today
+                    );
+                }
+                Ok(None) => {}
+                Err(e) => error!("Mission series ({}) occurrence failed: {}", series.id, e),
+            }
+        }
+
+        Ok(created)
+    }
+
+    async fn create_due_occurrence(
+        &self,
+        series: &MissionSeriesEntity,
+        today: NaiveDate,
+    ) -> Result<Option<i32>> {
+        let Some(scheduled_on) = series.next_occurrence_on else {
+            return Ok(None);
+        };
+        let rule = series.rule()?;
+
+        // Dates missed while the server was down are skipped, not back-filled
+        let occurrence_on = if scheduled_on < today {
+            rule.next_on_or_after(today, series.occurrences_created)
+        } else {
+            Some(scheduled_on)
+        };
+
+        let Some(occurrence_on) = occurrence_on.filter(|on| *on == today) else {
+            self.mission_series_repository
+                .advance(series.id, scheduled_on, occurrence_on)
+                .await?;
+            return Ok(None);
+        };
+
+        let next_occurrence_on = occurrence_on
+            .succ_opt()
+            .and_then(|tomorrow| rule.next_on_or_after(tomorrow, series.occurrences_created + 1));
+        self.mission_series_repository
+            .create_occurrence(
+                series.id,
+                scheduled_on,
+                occurrence_on,
+                next_occurrence_on,
+                series.to_occurrence(occurrence_on),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDateTime, NaiveTime};
+
+    use super::*;
+    use crate::domain::{
+        repositories::mission_series::MockMissionSeriesRepository,
+        value_objects::recurrence_frequencies::RecurrenceFrequencies,
+    };
+
+    const CHIEF_ID: i32 = 1;
+
+    fn series() -> MissionSeriesEntity {
+        let today = Utc::now().date_naive();
+        MissionSeriesEntity {
+            id: 1,
+            chief_id: CHIEF_ID,
+            name: "Weekly scrim".to_string(),
+            description: Some("Bring your main".to_string()),
+            max_crew: 3,
+            difficulty: "NORMAL".to_string(),
+            due_time: NaiveTime::from_hms_opt(20, 0, 0),
+            frequency: RecurrenceFrequencies::Daily,
+            weekdays: Vec::new(),
+            start_date: today,
+            end_date: Some(today + Duration::days(30)),
+            max_occurrences: Some(10),
+            occurrences_created: 0,
+            last_occurrence_on: None,
+            next_occurrence_on: Some(today),
+            is_active: true,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn edit_model() -> EditMissionSeriesModel {
+        EditMissionSeriesModel {
+            name: None,
+            description: None,
+            max_crew: None,
+            difficulty: None,
+            due_time: None,
+            frequency: None,
+            weekdays: None,
+            end_date: None,
+            max_occurrences: None,
+            clear_description: None,
+            clear_due_time: None,
+            clear_end_date: None,
+            clear_max_occurrences: None,
+        }
+    }
+
+    fn use_case(
+        expect_edit: impl Fn(&EditMissionSeriesEntity) -> bool + Send + 'static,
+    ) -> MissionSeriesUseCase<MockMissionSeriesRepository> {
+        let mut mission_series_repository = MockMissionSeriesRepository::new();
+        mission_series_repository
+            .expect_get_one()
+            .returning(|_| Ok(series()));
+        mission_series_repository
+            .expect_edit()
+            .withf(move |_, entity| expect_edit(entity))
+            .times(1)
+            .returning(|series_id, _| Ok(series_id));
+
+        MissionSeriesUseCase::new(
+            Arc::new(mission_series_repository),
+            Arc::new(DotEnvyConfig::for_tests()),
+        )
+    }
+
+    #[tokio::test]
+    async fn edit_keeps_optional_fields_that_are_left_out() {
+        let stored = series();
+        let use_case = use_case(move |entity| {
+            entity.description == stored.description
+                && entity.due_time == stored.due_time
+                && entity.end_date == stored.end_date
+                && entity.max_occurrences == stored.max_occurrences
+        });
+
+        use_case.edit(1, CHIEF_ID, edit_model()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn edit_clears_optional_fields_when_asked() {
+        let use_case = use_case(|entity| {
+            entity.description.is_none()
+                && entity.due_time.is_none()
+                && entity.end_date.is_none()
+                && entity.max_occurrences.is_none()
+        });
+
+        let edit_model = EditMissionSeriesModel {
+            clear_description: Some(true),
+            clear_due_time: Some(true),
+            clear_end_date: Some(true),
+            clear_max_occurrences: Some(true),
+            ..edit_model()
+        };
+        use_case.edit(1, CHIEF_ID, edit_model).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn edit_prefers_a_new_value_over_its_clear_flag() {
+        let end_date = Utc::now().date_naive() + Duration::days(7);
+        let use_case = use_case(move |entity| {
+            entity.end_date == Some(end_date) && entity.description.as_deref() == Some("New notes")
+        });
+
+        let edit_model = EditMissionSeriesModel {
+            description: Some("New notes".to_string()),
+            end_date: Some(end_date),
+            clear_description: Some(true),
+            clear_end_date: Some(true),
+            ..edit_model()
+        };
+        use_case.edit(1, CHIEF_ID, edit_model).await.unwrap();
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::{
+    application::use_cases::mission_management::MissionManagementUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::mission_templates::MissionTemplateEntity,
+        repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
+            mission_management::MissionManagementRepository,
+            mission_templates::MissionTemplateRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::{
+            mission_model::{AddMissionModel, normalize_difficulty},
+            mission_template_model::{
+                AddMissionTemplateModel, EditMissionTemplateModel, MissionFromTemplateModel,
+                TemplateScopes,
+            },
+        },
+    },
+};
+
+pub const MAX_TEMPLATES_PER_BRAWLER: i64 = 20;
+
+pub struct MissionTemplateUseCase<T1, T2, T3, T4, T5, T6>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    mission_template_repository: Arc<T1>,
+    mission_management_repository: Arc<T2>,
+    mission_viewing_repository: Arc<T3>,
+    crew_operation_repository: Arc<T4>,
+    achievement_repository: Arc<T5>,
+    notification_repository: Arc<T6>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T1, T2, T3, T4, T5, T6> MissionTemplateUseCase<T1, T2, T3, T4, T5, T6>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    pub fn new(
+        mission_template_repository: Arc<T1>,
+        mission_management_repository: Arc<T2>,
+        mission_viewing_repository: Arc<T3>,
+        crew_operation_repository: Arc<T4>,
+        achievement_repository: Arc<T5>,
+        notification_repository: Arc<T6>,
+        config: Arc<DotEnvyConfig>,
+    ) -> Self {
+        Self {
+            mission_template_repository,
+            mission_management_repository,
+            mission_viewing_repository,
+            crew_operation_repository,
+            achievement_repository,
+            notification_repository,
+            config,
+        }
+    }
+
+    fn validate(
+        &self,
+        name: Option<&str>,
+        max_crew: Option<i32>,
+        due_in_hours: Option<i32>,
+    ) -> Result<()> {
+        if let Some(name) = name
+            && name.trim().len() < 3
+        {
+            return Err(anyhow::anyhow!(
+                "Template name must be at least 3 characters long."
+            ));
+        }
+        if let Some(max_crew) = max_crew
+            && !(1..=self.config.max_crew_per_mission).contains(&max_crew)
+        {
+            return Err(anyhow::anyhow!(
+                "max_crew must be between 1 and {}",
+                self.config.max_crew_per_mission
+            ));
+        }
+        if let Some(due_in_hours) = due_in_hours
+            && due_in_hours < 1
+        {
+            return Err(anyhow::anyhow!("due_in_hours must be at least 1"));
+        }
+        Ok(())
+    }
+
+    pub async fn get_visible(
+        &self,
+        brawler_id: i32,
+        scope: Option<TemplateScopes>,
+    ) -> Result<Vec<MissionTemplateEntity>> {
+        self.mission_template_repository
+            .get_visible(brawler_id, scope)
+            .await
+    }
+
+    /// A brawler sees their own templates and the global ones.
+    pub async fn get_one(&self, template_id: i32, brawler_id: i32) -> Result<MissionTemplateEntity> {
+        let template = self.mission_template_repository.get_one(template_id).await?;
+        match template.owner_id {
+            Some(owner_id) if owner_id != brawler_id => {
+                Err(anyhow::anyhow!("Mission template not found"))
+            }
+            _ => Ok(template),
+        }
+    }
+
+    pub async fn add(&self, owner_id: i32, mut add_model: AddMissionTemplateModel) -> Result<i32> {
+        add_model.difficulty = normalize_difficulty(&add_model.difficulty)?;
+        self.validate(
+            Some(&add_model.name),
+            Some(add_model.max_crew),
+            add_model.due_in_hours,
+        )?;
+
+        let owned = self.mission_template_repository.count_owned(owner_id).await?;
+        if owned >= MAX_TEMPLATES_PER_BRAWLER {
+            return Err(anyhow::anyhow!(
+                "Template limit ({}) reached. Delete one before saving another.",
+                MAX_TEMPLATES_PER_BRAWLER
+            ));
+        }
+
+        self.mission_template_repository
+            .add(add_model.to_entity(Some(owner_id)))
+            .await
+    }
+
+    pub async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        mut edit_model: EditMissionTemplateModel,
+    ) -> Result<i32> {
+        edit_model.difficulty = edit_model
+            .difficulty
+            .as_deref()
+            .map(normalize_difficulty)
+            .transpose()?;
+        self.validate(
+            edit_model.name.as_deref(),
+            edit_model.max_crew,
+            edit_model.due_in_hours,
+        )?;
+        self.ensure_owned(template_id, owner_id).await?;
+
+        self.mission_template_repository
+            .edit(template_id, owner_id, edit_model.to_entity())
+            .await
+    }
+
+    pub async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        self.ensure_owned(template_id, owner_id).await?;
+
+        self.mission_template_repository
+            .remove(template_id, owner_id)
+            .await
+    }
+
+    async fn ensure_owned(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        let template = self.get_one(template_id, owner_id).await?;
+        if template.owner_id.is_none() {
+            return Err(anyhow::anyhow!(
+                "Global templates can only be changed by an admin"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Creates a mission from the template with the given overrides, through the same
+    /// validation and daily limit as creating one by hand.
+    pub async fn create_mission(
+        &self,
+        template_id: i32,
+        chief_id: i32,
+        overrides: MissionFromTemplateModel,
+    ) -> Result<i32> {
+        let template = self.get_one(template_id, chief_id).await?;
+
+        let due_date = overrides.due_date.or_else(|| {
+            template
+                .due_in_hours
+                .map(|hours| Utc::now().naive_utc() + Duration::hours(hours as i64))
+        });
+        let add_mission_model = AddMissionModel {
+            name: overrides.name.unwrap_or(template.name),
+            description: overrides.description.or(template.description),
+            max_crew: overrides.max_crew.unwrap_or(template.max_crew),
+            difficulty: overrides.difficulty.unwrap_or(template.difficulty),
+            due_date,
+            tags: overrides.tags,
+            requires_approval: overrides.requires_approval,
+            lanes: overrides.lanes,
+            rank_requirement: overrides.rank_requirement,
+        };
+
+        MissionManagementUseCase::new(
+            Arc::clone(&self.mission_management_repository),
+            Arc::clone(&self.mission_viewing_repository),
+            Arc::clone(&self.crew_operation_repository),
+            Arc::clone(&self.achievement_repository),
+            Arc::clone(&self.notification_repository),
+            Arc::clone(&self.config),
+        )
+        .add(chief_id, add_mission_model)
+        .await
+    }
+
+    /// Inserts or updates global templates by name. Returns (inserted, updated).
+    pub async fn seed_global(&self, mut templates: Vec<AddMissionTemplateModel>) -> Result<(usize, usize)> {
+        for template in &mut templates {
+            template.difficulty = normalize_difficulty(&template.difficulty)?;
+            self.validate(
+                Some(&template.name),
+                Some(template.max_crew),
+                template.due_in_hours,
+            )?;
+        }
+
+        let mut inserted = 0;
+        let mut updated = 0;
+        for template in templates {
+            if self
+                .mission_template_repository
+                .upsert_global(template.to_entity(None))
+                .await?
+            {
+                inserted += 1;
+            } else {
+                updated += 1;
+            }
+        }
+
+        Ok((inserted, updated))
+    }
+}
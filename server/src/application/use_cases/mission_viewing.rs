@@ -1,25 +1,41 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 
-use crate::domain::{
-    repositories::mission_viewing::MissionViewingRepository,
-    value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel},
+use crate::{
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::mission_status_history::MissionStatusHistoryEntity,
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::{
+            brawler_model::BrawlerModel, mission_filter::MissionFilter,
+            mission_lane_model::MissionLaneModel, mission_model::MissionModel,
+            mission_tag_model::TagCountModel,
+            page_model::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, Page, PageRequest},
+            tier_progress_model::TierProgressModel,
+        },
+    },
 };
+
+pub const POPULAR_TAGS_DEFAULT_LIMIT: i64 = 20;
+pub const POPULAR_TAGS_MAX_LIMIT: i64 = 100;
+
 pub struct MissionViewingUseCase<T>
 where
     T: MissionViewingRepository + Send + Sync,
 {
     mission_viewing_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
 }
 
 impl<T> MissionViewingUseCase<T>
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    pub fn new(mission_viewing_repository: Arc<T>) -> Self {
+    pub fn new(mission_viewing_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
         Self {
             mission_viewing_repository,
+            config,
         }
     }
 
@@ -31,21 +47,84 @@ where
             .await?;
 
         let model = self.mission_viewing_repository.get_one(mission_id).await?;
+        let tags = self
+            .mission_viewing_repository
+            .get_tags(&[mission_id])
+            .await?
+            .into_iter()
+            .map(|(_, tag)| tag)
+            .collect();
 
-        let result = model.to_model(crew_count);
+        let mut result = model.to_model(crew_count, tags);
+        result.lanes = self.mission_viewing_repository.get_lanes(&[mission_id]).await?;
 
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let results = self.mission_viewing_repository.get_all(filter).await?;
+    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Page<MissionModel>> {
+        filter.validate()?;
+        let mut filter = filter.clone();
+        filter.crew_ceiling = Some(self.config.max_crew_per_mission);
+        if filter.eligible.unwrap_or(false)
+            && let Some(viewer_id) = filter.viewer_id
+        {
+            let total_points = self.mission_viewing_repository.get_total_points(viewer_id).await?;
+            let thresholds = &self.config.tier.thresholds;
+            filter.viewer_rank = Some(TierProgressModel::from_points(total_points, thresholds).tier);
+            filter.viewer_total_points = Some(total_points);
+        }
+        let filter = &filter;
+
+        let page_request = PageRequest::resolve(
+            filter.page,
+            filter.limit,
+            filter.offset_token.as_deref(),
+            DEFAULT_PAGE_SIZE,
+            MAX_PAGE_SIZE,
+        )?;
+
+        let total = self.mission_viewing_repository.count_all(filter).await?;
+        let results = self
+            .mission_viewing_repository
+            .get_all(filter, page_request.limit, page_request.offset)
+            .await?;
+
+        let mission_ids: Vec<i32> = results.iter().map(|(entity, _)| entity.id).collect();
+        let mut tags_by_mission: HashMap<i32, Vec<String>> = HashMap::new();
+        for (mission_id, tag) in self.mission_viewing_repository.get_tags(&mission_ids).await? {
+            tags_by_mission.entry(mission_id).or_default().push(tag);
+        }
+
+        let mut lanes_by_mission: HashMap<i32, Vec<MissionLaneModel>> = HashMap::new();
+        for lane in self.mission_viewing_repository.get_lanes(&mission_ids).await? {
+            lanes_by_mission.entry(lane.mission_id).or_default().push(lane);
+        }
+
+        let mut snippets: HashMap<i32, String> = HashMap::new();
+        if let Some(search_query) = filter.search_query()
+            && !mission_ids.is_empty()
+        {
+            for snippet in self
+                .mission_viewing_repository
+                .get_search_snippets(&mission_ids, search_query)
+                .await?
+            {
+                snippets.insert(snippet.mission_id, snippet.snippet);
+            }
+        }
 
         let models = results
             .into_iter()
-            .map(|(entity, crew_count)| entity.to_model(crew_count))
+            .map(|(entity, crew_count)| {
+                let tags = tags_by_mission.remove(&entity.id).unwrap_or_default();
+                let mut model = entity.to_model(crew_count, tags);
+                model.lanes = lanes_by_mission.remove(&entity.id).unwrap_or_default();
+                model.snippet = snippets.remove(&entity.id);
+                model
+            })
             .collect();
 
-        Ok(models)
+        Ok(Page::new(models, total, page_request))
     }
     
     pub async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
@@ -56,4 +135,26 @@ where
 
         Ok(result)
     }
+
+    pub async fn get_status_history(&self, mission_id: i32) -> Result<Vec<MissionStatusHistoryEntity>> {
+        // Surfaces "not found" for missing or removed missions instead of an empty list
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let result = self
+            .mission_viewing_repository
+            .get_status_history(mission_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_popular_tags(&self, limit: Option<i64>) -> Result<Vec<TagCountModel>> {
+        let limit = limit
+            .unwrap_or(POPULAR_TAGS_DEFAULT_LIMIT)
+            .clamp(1, POPULAR_TAGS_MAX_LIMIT);
+
+        self.mission_viewing_repository
+            .get_popular_tags(limit)
+            .await
+    }
 }
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::notifications::{AddNotificationEntity, NotificationEntity},
+    repositories::notifications::NotificationRepository,
+    value_objects::{notification_kinds::NotificationKinds, page_model::PageRequest},
+};
+
+const DEFAULT_NOTIFICATION_LIMIT: i64 = 20;
+const MAX_NOTIFICATION_LIMIT: i64 = 100;
+
+pub struct NotificationUseCase<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    notification_repository: Arc<T>,
+}
+
+impl<T> NotificationUseCase<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    pub fn new(notification_repository: Arc<T>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    /// Sends the same message to every listed brawler.
+    pub async fn notify(
+        &self,
+        brawler_ids: &[i32],
+        kind: NotificationKinds,
+        mission_id: Option<i32>,
+        message: &str,
+    ) -> Result<usize> {
+        let notifications = brawler_ids
+            .iter()
+            .map(|brawler_id| AddNotificationEntity {
+                brawler_id: *brawler_id,
+                mission_id,
+                kind: kind.to_string(),
+                message: message.to_string(),
+            })
+            .collect();
+
+        self.notification_repository.add_many(notifications).await
+    }
+
+    pub async fn get_mine(
+        &self,
+        brawler_id: i32,
+        unread_only: bool,
+        page: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<Vec<NotificationEntity>> {
+        let page_request = PageRequest::resolve(
+            page,
+            limit,
+            None,
+            DEFAULT_NOTIFICATION_LIMIT,
+            MAX_NOTIFICATION_LIMIT,
+        )?;
+
+        self.notification_repository
+            .get_by_brawler(brawler_id, unread_only, page_request.limit, page_request.offset)
+            .await
+    }
+
+    pub async fn mark_read(&self, brawler_id: i32, notification_id: Option<i32>) -> Result<usize> {
+        self.notification_repository
+            .mark_read(brawler_id, notification_id)
+            .await
+    }
+}
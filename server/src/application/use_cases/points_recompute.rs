@@ -0,0 +1,167 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use tracing::info;
+
+use crate::{
+    application::use_cases::progression::DAILY_POINT_LIMIT,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::points_recompute::PointsRecomputeRepository,
+        value_objects::{
+            mission_statuses::MissionStatuses,
+            points_recompute_model::{PointsDiffModel, PointsRecomputeReportModel},
+        },
+    },
+};
+
+pub struct PointsRecomputeUseCase<T>
+where
+    T: PointsRecomputeRepository + Send + Sync,
+{
+    points_recompute_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T> PointsRecomputeUseCase<T>
+where
+    T: PointsRecomputeRepository + Send + Sync,
+{
+    pub fn new(points_recompute_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
+        Self {
+            points_recompute_repository,
+            config,
+        }
+    }
+
+    /// Replays mission history under the current scoring rules and, unless `dry_run`,
+    /// corrects every brawler whose total_points has drifted.
+    pub async fn recompute(&self, dry_run: bool) -> Result<PointsRecomputeReportModel> {
+        let scoring_env = &self.config.scoring;
+        let rating_env = &self.config.rating;
+
+        let mut changes = Vec::new();
+        let mut earned_per_day = HashMap::new();
+
+        // Participations come back in completion order so the daily cap plays out as it did live
+        for participation in self.points_recompute_repository.get_participations().await? {
+            if participation.status == MissionStatuses::Completed {
+                let base_points = participation.base_points as i64;
+                let prior_points = earned_per_day
+                    .entry((participation.brawler_id, participation.finished_at.date()))
+                    .or_insert(0_i64);
+
+                let allowed = (DAILY_POINT_LIMIT - *prior_points).max(0);
+                let to_add = base_points.min(allowed);
+                *prior_points += base_points;
+
+                if to_add > 0 {
+                    let delta = scoring_env
+                        .completion_adjustment(
+                            participation.due_date,
+                            participation.finished_at,
+                            to_add as i32,
+                        )
+                        .map_or(0, |(_, delta)| delta as i64);
+                    changes.push(PointsChange::award(
+                        participation.finished_at,
+                        participation.brawler_id,
+                        to_add + delta,
+                    ));
+                }
+            } else if participation.status == MissionStatuses::Failed {
+                let penalty = if participation.is_chief {
+                    scoring_env.failed_chief_penalty
+                } else {
+                    scoring_env.failed_crew_penalty
+                } as i64;
+                changes.push(PointsChange {
+                    at: participation.finished_at,
+                    brawler_id: participation.brawler_id,
+                    points: -penalty,
+                    clamped: true,
+                });
+            }
+        }
+
+        for mvp in self.points_recompute_repository.get_mvps().await? {
+            changes.push(PointsChange::award(
+                mvp.awarded_at,
+                mvp.brawler_id,
+                rating_env.mvp_bonus as i64,
+            ));
+        }
+
+        for entry in self.points_recompute_repository.get_other_ledger_points().await? {
+            changes.push(PointsChange::award(
+                entry.created_at,
+                entry.brawler_id,
+                entry.points as i64,
+            ));
+        }
+
+        // Failure penalties never take a brawler below zero, so how much one took depends on
+        // everything earned before it; replay every change in the order it happened
+        changes.sort_by_key(|change| change.at);
+        let mut expected: HashMap<i32, i64> = HashMap::new();
+        for change in changes {
+            let points = expected.entry(change.brawler_id).or_default();
+            if change.clamped {
+                *points -= (-change.points).min(*points).max(0);
+            } else {
+                *points += change.points;
+            }
+        }
+
+        let brawlers = self.points_recompute_repository.get_brawler_points().await?;
+        let brawlers_checked = brawlers.len();
+
+        let mut diffs = Vec::new();
+        for brawler in brawlers {
+            let earned = expected.get(&brawler.brawler_id).copied().unwrap_or(0);
+            let expected_points = i32::try_from(earned.max(0))?;
+
+            if expected_points != brawler.total_points {
+                diffs.push(PointsDiffModel {
+                    brawler_id: brawler.brawler_id,
+                    username: brawler.username,
+                    current_points: brawler.total_points,
+                    expected_points,
+                    diff: expected_points - brawler.total_points,
+                });
+            }
+        }
+
+        if !dry_run && !diffs.is_empty() {
+            self.points_recompute_repository.apply(diffs.clone()).await?;
+            info!("Recomputed points for {} brawlers", diffs.len());
+        }
+
+        Ok(PointsRecomputeReportModel {
+            dry_run,
+            brawlers_checked,
+            diffs,
+        })
+    }
+}
+
+/// One point change in a brawler's history, replayed in time order.
+struct PointsChange {
+    at: NaiveDateTime,
+    brawler_id: i32,
+    points: i64,
+    // Deducted like ProgressionUseCase::deduct_points, never below zero
+    clamped: bool,
+}
+
+impl PointsChange {
+    fn award(at: NaiveDateTime, brawler_id: i32, points: i64) -> Self {
+        Self {
+            at,
+            brawler_id,
+            points,
+            clamped: false,
+        }
+    }
+}
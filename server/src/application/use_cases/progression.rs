@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::{
+            brawler_level_ups::AddBrawlerLevelUpEntity, point_ledger::AddPointLedgerEntity,
+        },
+        repositories::brawlers::BrawlerRepository,
+        value_objects::{point_reasons::PointReasons, tier_progress_model::TierProgressModel},
+    },
+};
+
+// Most points a brawler can earn from completed missions per day
+pub const DAILY_POINT_LIMIT: i64 = 15;
+
+pub struct ProgressionUseCase<T>
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    brawler_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T> ProgressionUseCase<T>
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    pub fn new(brawler_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
+        Self {
+            brawler_repository,
+            config,
+        }
+    }
+
+    /// Every point change goes through here so tier promotions are never missed.
+    pub async fn award_points(
+        &self,
+        brawler_id: i32,
+        points: i32,
+        reason: PointReasons,
+        mission_id: Option<i32>,
+    ) -> Result<i32> {
+        let total_points = self
+            .brawler_repository
+            .add_points(AddPointLedgerEntity {
+                brawler_id,
+                points,
+                reason: reason.to_string(),
+                mission_id,
+            })
+            .await?;
+
+        let thresholds = &self.config.tier.thresholds;
+        let before = TierProgressModel::from_points(total_points - points, thresholds);
+        let after = TierProgressModel::from_points(total_points, thresholds);
+
+        if after.level > before.level {
+            self.brawler_repository
+                .record_level_up(AddBrawlerLevelUpEntity {
+                    brawler_id,
+                    from_tier: before.tier.to_string(),
+                    to_tier: after.tier.to_string(),
+                    total_points,
+                })
+                .await?;
+        }
+
+        Ok(total_points)
+    }
+
+    /// Takes points away without dropping the brawler below zero.
+    pub async fn deduct_points(
+        &self,
+        brawler_id: i32,
+        points: i32,
+        reason: PointReasons,
+        mission_id: Option<i32>,
+    ) -> Result<i32> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        let points = points.min(brawler.total_points);
+        if points <= 0 {
+            return Ok(brawler.total_points);
+        }
+
+        self.award_points(brawler_id, -points, reason, mission_id)
+            .await
+    }
+}
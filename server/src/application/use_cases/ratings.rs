@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::info;
+
+use crate::{
+    application::use_cases::progression::ProgressionUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::{
+            mission_ratings::{AddMissionMvpEntity, AddMissionRatingEntity, AddMvpVoteEntity},
+            missions::MissionEntity,
+        },
+        repositories::{
+            brawlers::BrawlerRepository, mission_viewing::MissionViewingRepository,
+            ratings::RatingRepository,
+        },
+        value_objects::{
+            mission_statuses::MissionStatuses,
+            point_reasons::PointReasons,
+            rating_model::{MissionFeedbackModel, RateBrawlerModel, RatingSummaryModel, VoteMvpModel},
+        },
+    },
+};
+
+pub struct RatingUseCase<T1, T2, T3>
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    rating_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    brawler_repository: Arc<T3>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T1, T2, T3> RatingUseCase<T1, T2, T3>
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    pub fn new(
+        rating_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        brawler_repository: Arc<T3>,
+        config: Arc<DotEnvyConfig>,
+    ) -> Self {
+        Self {
+            rating_repository,
+            mission_viewing_repository,
+            brawler_repository,
+            config,
+        }
+    }
+
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        rate_model: RateBrawlerModel,
+    ) -> Result<i32> {
+        if !(1..=5).contains(&rate_model.score) {
+            return Err(anyhow::anyhow!("Score must be between 1 and 5"));
+        }
+        if rate_model.ratee_id == rater_id {
+            return Err(anyhow::anyhow!("You cannot rate yourself"));
+        }
+
+        let participants = self.open_feedback_participants(mission_id, rater_id).await?;
+        if !participants.contains(&rate_model.ratee_id) {
+            return Err(anyhow::anyhow!("Brawler did not take part in this mission"));
+        }
+
+        let mut tags = Vec::new();
+        for tag in rate_model.tags.unwrap_or_default() {
+            let tag = tag.to_string();
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        self.rating_repository
+            .upsert_rating(AddMissionRatingEntity {
+                mission_id,
+                rater_id,
+                ratee_id: rate_model.ratee_id,
+                score: rate_model.score,
+                tags,
+            })
+            .await
+    }
+
+    pub async fn vote_mvp(
+        &self,
+        mission_id: i32,
+        voter_id: i32,
+        vote_model: VoteMvpModel,
+    ) -> Result<()> {
+        if vote_model.nominee_id == voter_id {
+            return Err(anyhow::anyhow!("You cannot vote for yourself"));
+        }
+
+        let participants = self.open_feedback_participants(mission_id, voter_id).await?;
+        if !participants.contains(&vote_model.nominee_id) {
+            return Err(anyhow::anyhow!("Brawler did not take part in this mission"));
+        }
+
+        self.rating_repository
+            .upsert_mvp_vote(AddMvpVoteEntity {
+                mission_id,
+                voter_id,
+                nominee_id: vote_model.nominee_id,
+            })
+            .await
+    }
+
+    pub async fn get_mission_feedback(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<MissionFeedbackModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.status != MissionStatuses::Completed {
+            return Err(anyhow::anyhow!("Mission has not been completed"));
+        }
+
+        let closes_at = Self::closes_at(&mission, self.config.rating.window_hours);
+
+        Ok(MissionFeedbackModel {
+            mission_id,
+            closes_at,
+            is_open: Utc::now().naive_utc() < closes_at,
+            my_ratings: self
+                .rating_repository
+                .get_ratings_by_rater(mission_id, brawler_id)
+                .await?,
+            my_mvp_vote: self
+                .rating_repository
+                .get_mvp_vote(mission_id, brawler_id)
+                .await?,
+            mvp_votes: self.rating_repository.get_mvp_tally(mission_id).await?,
+            mvp: self.rating_repository.get_mvp(mission_id).await?,
+        })
+    }
+
+    pub async fn get_summary(&self, brawler_id: i32) -> Result<RatingSummaryModel> {
+        let aggregate = self.rating_repository.get_aggregate(brawler_id).await?;
+        let tags = self.rating_repository.get_tag_counts(brawler_id).await?;
+
+        Ok(RatingSummaryModel {
+            brawler_id,
+            rating_average: aggregate.rating_average,
+            rating_count: aggregate.rating_count,
+            mvp_count: aggregate.mvp_count,
+            tags,
+        })
+    }
+
+    /// Picks the MVP of every mission whose voting window has closed and pays out the bonus.
+    pub async fn settle_mvps(&self) -> Result<()> {
+        let rating_env = &self.config.rating;
+        let completed_before = Utc::now().naive_utc() - Duration::hours(rating_env.window_hours);
+        let progression_use_case =
+            ProgressionUseCase::new(Arc::clone(&self.brawler_repository), Arc::clone(&self.config));
+
+        for mission_id in self
+            .rating_repository
+            .get_unsettled_mvp_missions(completed_before)
+            .await?
+        {
+            let tally = self.rating_repository.get_mvp_tally(mission_id).await?;
+            let Some(winner) = tally.first() else {
+                continue;
+            };
+
+            let awarded = self
+                .rating_repository
+                .award_mvp(AddMissionMvpEntity {
+                    mission_id,
+                    brawler_id: winner.nominee_id,
+                    votes: winner.votes as i32,
+                    bonus_points: rating_env.mvp_bonus,
+                })
+                .await?;
+
+            if !awarded {
+                continue;
+            }
+            if rating_env.mvp_bonus > 0 {
+                progression_use_case
+                    .award_points(
+                        winner.nominee_id,
+                        rating_env.mvp_bonus,
+                        PointReasons::MvpBonus,
+                        Some(mission_id),
+                    )
+                    .await?;
+            }
+            info!("Mission ({}) MVP is brawler ({})", mission_id, winner.nominee_id);
+        }
+
+        Ok(())
+    }
+
+    /// Returns everyone who took part in the mission, after checking that `brawler_id`
+    /// is one of them and the feedback window is still open.
+    async fn open_feedback_participants(&self, mission_id: i32, brawler_id: i32) -> Result<Vec<i32>> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.status != MissionStatuses::Completed {
+            return Err(anyhow::anyhow!(
+                "Mission must be Completed to rate. Current: {}",
+                mission.status
+            ));
+        }
+
+        let closes_at = Self::closes_at(&mission, self.config.rating.window_hours);
+        if Utc::now().naive_utc() >= closes_at {
+            return Err(anyhow::anyhow!("Rating window for this mission has closed"));
+        }
+
+        let mut participants = self.mission_viewing_repository.get_crew_ids(mission_id).await?;
+        participants.push(mission.chief_id);
+        if !participants.contains(&brawler_id) {
+            return Err(anyhow::anyhow!("Only the chief and crew of this mission can rate"));
+        }
+
+        Ok(participants)
+    }
+
+    // The window opens at completion; edits and chief transfers after it don't move it
+    fn closes_at(mission: &MissionEntity, window_hours: i64) -> NaiveDateTime {
+        mission.finished_at.unwrap_or(mission.updated_at) + Duration::hours(window_hours)
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::rewards::RewardEntity,
+    repositories::rewards::RewardRepository,
+    value_objects::{
+        reward_kinds::RewardKinds,
+        reward_model::{OwnedRewardModel, RedemptionReceiptModel},
+    },
+};
+
+pub struct RewardUseCase<T>
+where
+    T: RewardRepository + Send + Sync,
+{
+    reward_repository: Arc<T>,
+}
+
+impl<T> RewardUseCase<T>
+where
+    T: RewardRepository + Send + Sync,
+{
+    pub fn new(reward_repository: Arc<T>) -> Self {
+        Self { reward_repository }
+    }
+
+    pub async fn get_catalog(&self, kind: Option<RewardKinds>) -> Result<Vec<RewardEntity>> {
+        self.reward_repository
+            .get_catalog(kind.map(|kind| kind.to_string()))
+            .await
+    }
+
+    pub async fn get_owned(&self, brawler_id: i32) -> Result<Vec<OwnedRewardModel>> {
+        self.reward_repository.get_owned(brawler_id).await
+    }
+
+    pub async fn redeem(&self, brawler_id: i32, reward_id: i32) -> Result<RedemptionReceiptModel> {
+        self.reward_repository.redeem(brawler_id, reward_id).await
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use tracing::info;
+
+use crate::{
+    config::config_model::DotEnvyConfig,
+    domain::{
+        entities::seasons::{AddSeasonEntity, SeasonEntity},
+        repositories::seasons::SeasonRepository,
+        value_objects::season_model::{SeasonLeaderboardModel, SeasonStandingModel},
+    },
+};
+
+const DEFAULT_STANDINGS_LIMIT: i64 = 10;
+const MAX_STANDINGS_LIMIT: i64 = 100;
+
+pub struct SeasonUseCase<T>
+where
+    T: SeasonRepository + Send + Sync,
+{
+    season_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T> SeasonUseCase<T>
+where
+    T: SeasonRepository + Send + Sync,
+{
+    pub fn new(season_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
+        Self {
+            season_repository,
+            config,
+        }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<SeasonEntity>> {
+        self.season_repository.get_all().await
+    }
+
+    pub async fn get_current_leaderboard(&self, limit: Option<i64>) -> Result<SeasonLeaderboardModel> {
+        let season = self
+            .season_repository
+            .get_current()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("No season is currently running"))?;
+
+        let standings = self
+            .season_repository
+            .get_live_standings(season.id, Self::clamp_limit(limit))
+            .await?;
+
+        Ok(SeasonLeaderboardModel { season, standings })
+    }
+
+    pub async fn get_final_standings(
+        &self,
+        season_id: i32,
+        limit: Option<i64>,
+    ) -> Result<SeasonLeaderboardModel> {
+        let season = self.season_repository.get_one(season_id).await?;
+        if season.archived_at.is_none() {
+            return Err(anyhow::anyhow!("{} has not ended yet", season.name));
+        }
+
+        let standings: Vec<SeasonStandingModel> = self
+            .season_repository
+            .get_archived_standings(season_id, Self::clamp_limit(limit))
+            .await?;
+
+        Ok(SeasonLeaderboardModel { season, standings })
+    }
+
+    /// End-of-season job: snapshots every finished season and opens the next one.
+    pub async fn rollover(&self) -> Result<()> {
+        for season in self.season_repository.get_ended_unarchived().await? {
+            let archived = self.season_repository.archive(season.id).await?;
+            info!("Archived {} with {} standings", season.name, archived);
+        }
+
+        if self.season_repository.get_current().await?.is_some() {
+            return Ok(());
+        }
+
+        let now = Utc::now().naive_utc();
+        let latest = self.season_repository.get_latest().await?;
+        if latest.as_ref().is_some_and(|s| s.ends_at > now) {
+            // Next season is already scheduled
+            return Ok(());
+        }
+
+        let season_number = self.season_repository.get_all().await?.len() + 1;
+        let season_id = self
+            .season_repository
+            .add(AddSeasonEntity {
+                name: format!("Season {}", season_number),
+                starts_at: now,
+                ends_at: now + Duration::days(self.config.season.length_days),
+            })
+            .await?;
+        info!("Started Season {} (id: {})", season_number, season_id);
+
+        Ok(())
+    }
+
+    fn clamp_limit(limit: Option<i64>) -> i64 {
+        limit
+            .unwrap_or(DEFAULT_STANDINGS_LIMIT)
+            .clamp(1, MAX_STANDINGS_LIMIT)
+    }
+}
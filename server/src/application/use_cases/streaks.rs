@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::{
+    application::use_cases::progression::ProgressionUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{repositories::brawlers::BrawlerRepository, value_objects::{point_reasons::PointReasons, streak_model::StreakModel}},
+};
+
+pub struct StreakUseCase<T>
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    brawler_repository: Arc<T>,
+    config: Arc<DotEnvyConfig>,
+}
+
+impl<T> StreakUseCase<T>
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    pub fn new(brawler_repository: Arc<T>, config: Arc<DotEnvyConfig>) -> Self {
+        Self {
+            brawler_repository,
+            config,
+        }
+    }
+
+    /// Counts today as an active day for the brawler and pays out any milestone bonus.
+    pub async fn record_activity(&self, brawler_id: i32) -> Result<StreakModel> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        let today = Utc::now().date_naive();
+
+        let Some(streak) = brawler.streak().advance(today) else {
+            return Ok(brawler.streak());
+        };
+
+        self.brawler_repository
+            .update_streak(brawler_id, streak.clone())
+            .await?;
+
+        if let Some(bonus) = self.config.streak.bonus_for(streak.current_streak) {
+            ProgressionUseCase::new(Arc::clone(&self.brawler_repository), Arc::clone(&self.config))
+                .award_points(brawler_id, bonus, PointReasons::StreakBonus, None)
+                .await?;
+        }
+
+        Ok(streak)
+    }
+}
@@ -1,7 +1,11 @@
 use anyhow::Result;
 
+use crate::domain::value_objects::rank_tiers::RankTiers;
 use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
+    config_model::{
+        ArchiveEnv, CloudinaryEnv, Database, DueDateEnv, MissionSeriesEnv, DotEnvyConfig, JwtEnv, RatingEnv, ScoringEnv, SeasonEnv, Server,
+        StreakEnv, TierEnv,
+    },
     stage::Stage,
 };
 
@@ -30,10 +34,28 @@ pub fn load() -> Result<DotEnvyConfig> {
         .expect("SECRET is valid")
         .parse()?;
 
+    let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
+        .unwrap_or("5".to_string())
+        .trim()
+        .parse::<i32>()?;
+    if max_crew_per_mission < 1 {
+        return Err(anyhow::anyhow!("MAX_CREW_PER_MISSION must be at least 1"));
+    }
+
+    // Read once here so request handlers and sweeps never re-parse the environment
     let config = DotEnvyConfig {
         server,
         database,
         secret,
+        max_crew_per_mission,
+        streak: load_streak_env()?,
+        tier: load_tier_env()?,
+        season: load_season_env()?,
+        scoring: load_scoring_env()?,
+        rating: load_rating_env()?,
+        due_date: load_due_date_env()?,
+        mission_series: load_mission_series_env()?,
+        archive: load_archive_env()?,
     };
 
     Ok(config)
@@ -64,6 +86,194 @@ pub fn get_jwt_env() -> Result<JwtEnv> {
     })
 }
 
+// STREAK_MILESTONE_BONUSES="3:2,7:5,30:20" -> 2 bonus points on a 3-day streak, ...
+fn load_streak_env() -> Result<StreakEnv> {
+    let raw = std::env::var("STREAK_MILESTONE_BONUSES").unwrap_or_default();
+
+    let mut milestone_bonuses = Vec::new();
+    for pair in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (days, points) = pair
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid streak milestone: {}", pair))?;
+        let (days, points) = (days.trim().parse::<i32>()?, points.trim().parse::<i32>()?);
+        if days < 1 || points < 1 {
+            return Err(anyhow::anyhow!(
+                "STREAK_MILESTONE_BONUSES days and points must be positive: {}",
+                pair
+            ));
+        }
+        milestone_bonuses.push((days, points));
+    }
+
+    Ok(StreakEnv { milestone_bonuses })
+}
+
+fn load_tier_env() -> Result<TierEnv> {
+    let raw = std::env::var("RANK_TIER_THRESHOLDS")
+        .unwrap_or("0,50,150,300,600,1000".to_string());
+
+    let thresholds = raw
+        .split(',')
+        .map(|t| t.trim().parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()?;
+
+    if thresholds.len() != RankTiers::ALL.len() {
+        return Err(anyhow::anyhow!(
+            "RANK_TIER_THRESHOLDS must have {} values",
+            RankTiers::ALL.len()
+        ));
+    }
+    if thresholds.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(anyhow::anyhow!("RANK_TIER_THRESHOLDS must be strictly ascending"));
+    }
+
+    Ok(TierEnv { thresholds })
+}
+
+fn load_season_env() -> Result<SeasonEnv> {
+    let length_days = std::env::var("SEASON_LENGTH_DAYS")
+        .unwrap_or("30".to_string())
+        .trim()
+        .parse::<i64>()?;
+
+    let rollover_interval_secs = std::env::var("SEASON_ROLLOVER_INTERVAL_SECS")
+        .unwrap_or("3600".to_string())
+        .trim()
+        .parse::<u64>()?;
+
+    if length_days <= 0 || rollover_interval_secs == 0 {
+        return Err(anyhow::anyhow!(
+            "SEASON_LENGTH_DAYS and SEASON_ROLLOVER_INTERVAL_SECS must be positive"
+        ));
+    }
+
+    Ok(SeasonEnv {
+        length_days,
+        rollover_interval_secs,
+    })
+}
+
+fn load_scoring_env() -> Result<ScoringEnv> {
+    let read = |key: &str, default: &str| -> Result<i32> {
+        Ok(std::env::var(key)
+            .unwrap_or(default.to_string())
+            .trim()
+            .parse::<i32>()?)
+    };
+
+    let scoring_env = ScoringEnv {
+        early_bonus_percent: read("EARLY_COMPLETION_BONUS_PERCENT", "20")?,
+        late_penalty_percent: read("LATE_COMPLETION_PENALTY_PERCENT", "50")?,
+        failed_chief_penalty: read("FAILED_MISSION_CHIEF_PENALTY", "0")?,
+        failed_crew_penalty: read("FAILED_MISSION_CREW_PENALTY", "0")?,
+    };
+
+    if scoring_env.early_bonus_percent < 0
+        || !(0..=100).contains(&scoring_env.late_penalty_percent)
+        || scoring_env.failed_chief_penalty < 0
+        || scoring_env.failed_crew_penalty < 0
+    {
+        return Err(anyhow::anyhow!(
+            "Scoring modifiers must be non-negative and LATE_COMPLETION_PENALTY_PERCENT at most 100"
+        ));
+    }
+
+    Ok(scoring_env)
+}
+
+fn load_rating_env() -> Result<RatingEnv> {
+    let window_hours = std::env::var("RATING_WINDOW_HOURS")
+        .unwrap_or("48".to_string())
+        .trim()
+        .parse::<i64>()?;
+
+    let mvp_bonus = std::env::var("MVP_BONUS_POINTS")
+        .unwrap_or("5".to_string())
+        .trim()
+        .parse::<i32>()?;
+
+    let settle_interval_secs = std::env::var("MVP_SETTLE_INTERVAL_SECS")
+        .unwrap_or("300".to_string())
+        .trim()
+        .parse::<u64>()?;
+
+    if window_hours <= 0 || mvp_bonus < 0 || settle_interval_secs == 0 {
+        return Err(anyhow::anyhow!(
+            "RATING_WINDOW_HOURS and MVP_SETTLE_INTERVAL_SECS must be positive, MVP_BONUS_POINTS non-negative"
+        ));
+    }
+
+    Ok(RatingEnv {
+        window_hours,
+        mvp_bonus,
+        settle_interval_secs,
+    })
+}
+
+fn load_due_date_env() -> Result<DueDateEnv> {
+    let check_interval_secs = std::env::var("DUE_DATE_CHECK_INTERVAL_SECS")
+        .unwrap_or("60".to_string())
+        .trim()
+        .parse::<u64>()?;
+
+    let grace_minutes = std::env::var("DUE_DATE_GRACE_MINUTES")
+        .unwrap_or("15".to_string())
+        .trim()
+        .parse::<i64>()?;
+
+    let reminder_minutes = std::env::var("DUE_DATE_REMINDER_MINUTES")
+        .unwrap_or("60".to_string())
+        .trim()
+        .parse::<i64>()?;
+
+    if check_interval_secs == 0 || grace_minutes < 0 || reminder_minutes < 0 {
+        return Err(anyhow::anyhow!(
+            "DUE_DATE_CHECK_INTERVAL_SECS must be positive, DUE_DATE_GRACE_MINUTES and DUE_DATE_REMINDER_MINUTES non-negative"
+        ));
+    }
+
+    Ok(DueDateEnv {
+        check_interval_secs,
+        grace_minutes,
+        reminder_minutes,
+    })
+}
+
+fn load_mission_series_env() -> Result<MissionSeriesEnv> {
+    let interval_secs = std::env::var("MISSION_SERIES_INTERVAL_SECS")
+        .unwrap_or("300".to_string())
+        .trim()
+        .parse::<u64>()?;
+
+    if interval_secs == 0 {
+        return Err(anyhow::anyhow!("MISSION_SERIES_INTERVAL_SECS must be positive"));
+    }
+
+    Ok(MissionSeriesEnv { interval_secs })
+}
+
+fn load_archive_env() -> Result<ArchiveEnv> {
+    let archive_after_hours = std::env::var("MISSION_ARCHIVE_AFTER_HOURS")
+        .unwrap_or("168".to_string())
+        .trim()
+        .parse::<i64>()?;
+
+    let interval_secs = std::env::var("MISSION_ARCHIVE_INTERVAL_SECS")
+        .unwrap_or("3600".to_string())
+        .trim()
+        .parse::<u64>()?;
+
+    if archive_after_hours < 0 || interval_secs == 0 {
+        return Err(anyhow::anyhow!(
+            "MISSION_ARCHIVE_AFTER_HOURS must be non-negative and MISSION_ARCHIVE_INTERVAL_SECS positive"
+        ));
+    }
+
+    Ok(ArchiveEnv {
+        archive_after_hours,
+        interval_secs,
+    })
+}
 
 pub fn get_cloundinary_env() -> Result<CloudinaryEnv> {
     dotenvy::dotenv().ok();
@@ -1,3 +1,7 @@
+use chrono::NaiveDateTime;
+
+use crate::domain::value_objects::point_reasons::PointReasons;
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
@@ -15,7 +19,16 @@ pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
     pub secret: String,
-    // pub max_crew_per_mission: u32,
+    // Global ceiling for missions.max_crew
+    pub max_crew_per_mission: i32,
+    pub streak: StreakEnv,
+    pub tier: TierEnv,
+    pub season: SeasonEnv,
+    pub scoring: ScoringEnv,
+    pub rating: RatingEnv,
+    pub due_date: DueDateEnv,
+    pub mission_series: MissionSeriesEnv,
+    pub archive: ArchiveEnv,
 }
 
 #[derive(Debug, Clone)]
@@ -24,9 +37,140 @@ pub struct JwtEnv {
     pub life_time_days: i64,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct StreakEnv {
+    // (streak days, bonus points)
+    pub milestone_bonuses: Vec<(i32, i32)>,
+}
+
+impl StreakEnv {
+    pub fn bonus_for(&self, streak_days: i32) -> Option<i32> {
+        self.milestone_bonuses
+            .iter()
+            .find(|(days, _)| *days == streak_days)
+            .map(|(_, points)| *points)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TierEnv {
+    // Minimum total_points for Bronze, Silver, Gold, Platinum, Diamond, Conqueror
+    pub thresholds: Vec<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SeasonEnv {
+    pub length_days: i64,
+    pub rollover_interval_secs: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScoringEnv {
+    // Extra percentage of the earned points when completed before due_date
+    pub early_bonus_percent: i32,
+    // Percentage of the earned points taken back when completed after due_date
+    pub late_penalty_percent: i32,
+    // Points deducted when a mission is moved to Failed
+    pub failed_chief_penalty: i32,
+    pub failed_crew_penalty: i32,
+}
+
+impl ScoringEnv {
+    /// Returns the due-date adjustment for `points` earned on a mission, if any.
+    pub fn completion_adjustment(
+        &self,
+        due_date: Option<NaiveDateTime>,
+        completed_at: NaiveDateTime,
+        points: i32,
+    ) -> Option<(PointReasons, i32)> {
+        let due_date = due_date?;
+        let (reason, delta) = if completed_at <= due_date {
+            (PointReasons::EarlyCompletionBonus, points * self.early_bonus_percent / 100)
+        } else {
+            (PointReasons::LateCompletionPenalty, -(points * self.late_penalty_percent / 100))
+        };
+
+        (delta != 0).then_some((reason, delta))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RatingEnv {
+    // How long after completion ratings and MVP votes are accepted
+    pub window_hours: i64,
+    pub mvp_bonus: i32,
+    pub settle_interval_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DueDateEnv {
+    pub check_interval_secs: u64,
+    // How long past due_date a mission may run before it is failed
+    pub grace_minutes: i64,
+    // How long before due_date the crew is reminded
+    pub reminder_minutes: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissionSeriesEnv {
+    pub interval_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchiveEnv {
+    // How long a finished mission stays in default listings
+    pub archive_after_hours: i64,
+    pub interval_secs: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct CloudinaryEnv {
     pub cloud_name: String,
     pub api_key: String,
     pub api_secret: String,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+impl DotEnvyConfig {
+    /// The loader's defaults, for tests that never read the environment.
+    pub fn for_tests() -> Self {
+        Self {
+            server: Server {
+                port: 8080,
+                body_limit: 10,
+                timeout: 30,
+            },
+            database: Database { url: String::new() },
+            secret: String::new(),
+            max_crew_per_mission: 5,
+            streak: StreakEnv::default(),
+            tier: TierEnv {
+                thresholds: vec![0, 50, 150, 300, 600, 1000],
+            },
+            season: SeasonEnv {
+                length_days: 30,
+                rollover_interval_secs: 3600,
+            },
+            scoring: ScoringEnv {
+                early_bonus_percent: 20,
+                late_penalty_percent: 50,
+                failed_chief_penalty: 0,
+                failed_crew_penalty: 0,
+            },
+            rating: RatingEnv {
+                window_hours: 48,
+                mvp_bonus: 5,
+                settle_interval_secs: 300,
+            },
+            due_date: DueDateEnv {
+                check_interval_secs: 60,
+                grace_minutes: 15,
+                reminder_minutes: 60,
+            },
+            mission_series: MissionSeriesEnv { interval_secs: 300 },
+            archive: ArchiveEnv {
+                archive_after_hours: 168,
+                interval_secs: 3600,
+            },
+        }
+    }
+}
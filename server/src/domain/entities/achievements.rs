@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{achievements, brawler_achievements};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = achievements)]
+pub struct AchievementEntity {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub icon: String,
+    pub criteria: String,
+    pub threshold: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct UnlockAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement_id: i32,
+}
@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::brawler_level_ups;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = brawler_level_ups)]
+pub struct BrawlerLevelUpEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub total_points: i32,
+    pub seen_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_level_ups)]
+pub struct AddBrawlerLevelUpEntity {
+    pub brawler_id: i32,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub total_points: i32,
+}
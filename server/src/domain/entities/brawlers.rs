@@ -1,5 +1,11 @@
-use crate::{infrastructure::{ database::{ schema::brawlers}}};
-use chrono::NaiveDateTime;
+use crate::{
+    domain::value_objects::{
+        achievement_model::UnlockedAchievementModel, reward_model::OwnedRewardModel,
+        streak_model::StreakModel, tier_progress_model::TierProgressModel,
+    },
+    infrastructure::database::schema::brawlers,
+};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use diesel::{Selectable, prelude::*};
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +16,19 @@ pub struct Brawler {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub total_points: i32, 
+    pub spendable_points: i32,
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub tier: TierProgressModel,
+    pub badges: Vec<UnlockedAchievementModel>,
+    pub cosmetics: Vec<OwnedRewardModel>,
+}
+
+impl Brawler {
+    pub fn with_tier(mut self, thresholds: &[i32]) -> Self {
+        self.tier = TierProgressModel::from_points(self.total_points, thresholds);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -24,16 +43,37 @@ pub struct BrawlerEntity {
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
     pub total_points: i32,  
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub last_active_date: Option<NaiveDate>,
+    pub spendable_points: i32,
 }   
 
+impl BrawlerEntity {
+    pub fn streak(&self) -> StreakModel {
+        StreakModel {
+            current_streak: self.current_streak,
+            longest_streak: self.longest_streak,
+            last_active_date: self.last_active_date,
+        }
+    }
+}
+
 impl From<BrawlerEntity> for Brawler {
     fn from(entity: BrawlerEntity) -> Self {
+        let current_streak = entity.streak().current_as_of(Utc::now().date_naive());
         Self {
             id: entity.id,
             username: entity.username,
             display_name: entity.display_name,
             avatar_url: entity.avatar_url,
             total_points: entity.total_points,
+            spendable_points: entity.spendable_points,
+            current_streak,
+            longest_streak: entity.longest_streak,
+            tier: TierProgressModel::default(),
+            badges: Vec::new(),
+            cosmetics: Vec::new(),
         }
     }
 }
@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::value_objects::join_request_statuses::JoinRequestStatuses,
+    infrastructure::database::schema::join_requests,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = join_requests)]
+pub struct JoinRequestEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub status: JoinRequestStatuses,
+    pub decided_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = join_requests)]
+pub struct AddJoinRequestEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub status: JoinRequestStatuses,
+}
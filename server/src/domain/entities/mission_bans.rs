@@ -0,0 +1,12 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_bans;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_bans)]
+pub struct AddMissionBanEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub banned_by: i32,
+    pub reason: Option<String>,
+}
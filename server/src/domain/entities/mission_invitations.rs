@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::value_objects::mission_invitation_model::{InvitationStatuses, MissionInvitationModel},
+    infrastructure::database::schema::mission_invitations,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_invitations)]
+pub struct MissionInvitationEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+    pub status: InvitationStatuses,
+    pub expires_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionInvitationEntity {
+    pub fn to_model(&self, mission_name: String) -> MissionInvitationModel {
+        MissionInvitationModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            mission_name,
+            brawler_id: self.brawler_id,
+            invited_by: self.invited_by,
+            status: self.status,
+            expires_at: self.expires_at,
+            decided_at: self.decided_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_invitations)]
+pub struct AddMissionInvitationEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+    pub status: InvitationStatuses,
+    pub expires_at: NaiveDateTime,
+}
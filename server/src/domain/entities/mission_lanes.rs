@@ -0,0 +1,13 @@
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::lanes::Lanes, infrastructure::database::schema::mission_lanes,
+};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_lanes)]
+pub struct AddMissionLaneEntity {
+    pub mission_id: i32,
+    pub lane: Lanes,
+    pub slots: i32,
+}
@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::{mission_mvp_votes, mission_mvps, mission_ratings};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_ratings)]
+pub struct MissionRatingEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_ratings)]
+pub struct AddMissionRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_mvp_votes)]
+pub struct AddMvpVoteEntity {
+    pub mission_id: i32,
+    pub voter_id: i32,
+    pub nominee_id: i32,
+}
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_mvps)]
+#[diesel(primary_key(mission_id))]
+pub struct MissionMvpEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub votes: i32,
+    pub bonus_points: i32,
+    pub awarded_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_mvps)]
+pub struct AddMissionMvpEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub votes: i32,
+    pub bonus_points: i32,
+}
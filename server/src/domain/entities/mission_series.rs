@@ -0,0 +1,114 @@
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        entities::missions::AddMissionEntity,
+        value_objects::{
+            mission_model::AddMissionModel, rank_requirement_model::RankRequirementModel,
+            recurrence_frequencies::RecurrenceFrequencies, recurrence_rule::RecurrenceRule,
+        },
+    },
+    infrastructure::database::schema::mission_series,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_series)]
+pub struct MissionSeriesEntity {
+    pub id: i32,
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: i32,
+    pub difficulty: String,
+    pub due_time: Option<NaiveTime>,
+    pub frequency: RecurrenceFrequencies,
+    pub weekdays: Vec<String>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+    pub occurrences_created: i32,
+    pub last_occurrence_on: Option<NaiveDate>,
+    pub next_occurrence_on: Option<NaiveDate>,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionSeriesEntity {
+    pub fn rule(&self) -> Result<RecurrenceRule> {
+        let weekdays = self
+            .weekdays
+            .iter()
+            .map(|weekday| {
+                weekday
+                    .parse::<Weekday>()
+                    .map_err(|_| anyhow::anyhow!("Invalid weekday: {}", weekday))
+            })
+            .collect::<Result<Vec<Weekday>>>()?;
+
+        Ok(RecurrenceRule {
+            frequency: self.frequency,
+            weekdays,
+            end_date: self.end_date,
+            max_occurrences: self.max_occurrences,
+        })
+    }
+
+    /// The mission row for the occurrence on `date`, built from the series template.
+    pub fn to_occurrence(&self, date: NaiveDate) -> AddMissionEntity {
+        let add_mission_model = AddMissionModel {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            max_crew: self.max_crew,
+            difficulty: self.difficulty.clone(),
+            due_date: self.due_time.map(|due_time| date.and_time(due_time)),
+            tags: None,
+            requires_approval: None,
+            lanes: None,
+            rank_requirement: RankRequirementModel::default(),
+        };
+
+        AddMissionEntity {
+            series_id: Some(self.id),
+            ..add_mission_model.to_entity(self.chief_id)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_series)]
+pub struct AddMissionSeriesEntity {
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: i32,
+    pub difficulty: String,
+    pub due_time: Option<NaiveTime>,
+    pub frequency: RecurrenceFrequencies,
+    pub weekdays: Vec<String>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+    pub next_occurrence_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_series)]
+#[diesel(treat_none_as_null = true)]
+pub struct EditMissionSeriesEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: i32,
+    pub difficulty: String,
+    pub due_time: Option<NaiveTime>,
+    pub frequency: RecurrenceFrequencies,
+    pub weekdays: Vec<String>,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+    pub next_occurrence_on: Option<NaiveDate>,
+    pub is_active: bool,
+}
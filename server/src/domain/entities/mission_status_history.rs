@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::value_objects::mission_statuses::MissionStatuses,
+    infrastructure::database::schema::mission_status_history,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_status_history)]
+pub struct MissionStatusHistoryEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub from_status: Option<MissionStatuses>,
+    pub to_status: MissionStatuses,
+    pub actor_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_status_history)]
+pub struct AddMissionStatusHistoryEntity {
+    pub mission_id: i32,
+    pub from_status: Option<MissionStatuses>,
+    pub to_status: MissionStatuses,
+    pub actor_id: Option<i32>,
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::mission_templates;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_templates)]
+pub struct MissionTemplateEntity {
+    pub id: i32,
+    pub owner_id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: String,
+    pub max_crew: i32,
+    pub due_in_hours: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_templates)]
+pub struct AddMissionTemplateEntity {
+    pub owner_id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: String,
+    pub max_crew: i32,
+    pub due_in_hours: Option<i32>,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_templates)]
+pub struct EditMissionTemplateEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub max_crew: Option<i32>,
+    pub due_in_hours: Option<i32>,
+}
@@ -3,7 +3,11 @@ use diesel::prelude::*;
 // use serde_json::de; 
 
 use crate::{
-    domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
+    domain::value_objects::{
+        mission_model::MissionModel, mission_statuses::MissionStatuses,
+        rank_requirement_model::RankRequirementModel, rank_tiers::RankTiers,
+    },
+    infrastructure::database::schema::missions,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -13,7 +17,7 @@ pub struct MissionEntity {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
@@ -22,16 +26,36 @@ pub struct MissionEntity {
     pub difficulty: String,               
     pub base_points: i32,                 
     pub due_date: Option<NaiveDateTime>,  
+    pub archived_at: Option<NaiveDateTime>,
+    pub series_id: Option<i32>,
+    pub requires_approval: bool,
+    pub min_rank: Option<RankTiers>,
+    pub max_rank: Option<RankTiers>,
+    pub min_total_points: Option<i32>,
+    pub finished_at: Option<NaiveDateTime>,
 }
 
 
 impl MissionEntity {
-    pub fn to_model(&self, crew_count: i64) -> MissionModel {
+    /// Crew slots actually available, capped by the global MAX_CREW_PER_MISSION ceiling.
+    pub fn crew_capacity(&self, max_crew_per_mission: i32) -> i32 {
+        self.max_crew.min(max_crew_per_mission)
+    }
+
+    pub fn rank_requirement(&self) -> RankRequirementModel {
+        RankRequirementModel {
+            min_rank: self.min_rank,
+            max_rank: self.max_rank,
+            min_total_points: self.min_total_points,
+        }
+    }
+
+    pub fn to_model(&self, crew_count: i64, tags: Vec<String>) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
+            status: self.status,
             chief_id: self.chief_id,
             crew_count,
             created_at: self.created_at,
@@ -40,6 +64,13 @@ impl MissionEntity {
             difficulty: self.difficulty.clone(), 
             base_points: self.base_points,       
             due_date: self.due_date,             
+            archived_at: self.archived_at,
+            series_id: self.series_id,
+            requires_approval: self.requires_approval,
+            rank_requirement: self.rank_requirement(),
+            tags,
+            lanes: Vec::new(),
+            snippet: None,
         }
     }
 }
@@ -49,23 +80,33 @@ impl MissionEntity {
 pub struct AddMissionEntity {
     pub chief_id: i32,
     pub name: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub description: Option<String>,
     pub max_crew: i32,
     pub difficulty: String,               
     pub base_points: i32,               
     pub due_date: Option<NaiveDateTime>, 
+    pub series_id: Option<i32>,
+    pub requires_approval: bool,
+    pub min_rank: Option<RankTiers>,
+    pub max_rank: Option<RankTiers>,
+    pub min_total_points: Option<i32>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
 #[diesel(table_name = missions)]
 pub struct EditMissionEntity {
-    pub chief_id: i32,
     pub name: Option<String>,
-    pub status: Option<String>,
     pub description: Option<String>,
     pub max_crew: Option<i32>,
     pub difficulty: Option<String>,               
     pub base_points: Option<i32>,                 
     pub due_date: Option<NaiveDateTime>, 
+    pub requires_approval: Option<bool>,
+    // Some(None) clears the bound
+    pub min_rank: Option<Option<RankTiers>>,
+    pub max_rank: Option<Option<RankTiers>>,
+    pub min_total_points: Option<Option<i32>>,
+    // Some(None) clears the reminder marker so a moved deadline gets a fresh reminder
+    pub due_reminded_at: Option<Option<NaiveDateTime>>,
 }        
\ No newline at end of file
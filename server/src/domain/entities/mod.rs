@@ -1,3 +1,19 @@
+pub mod achievements;
+pub mod brawler_level_ups;
 pub mod brawlers;
 pub mod crew_memberships;
+pub mod join_requests;
+pub mod mission_bans;
+pub mod mission_invitations;
+pub mod mission_lanes;
+pub mod mission_ratings;
+pub mod mission_series;
+pub mod mission_status_history;
+pub mod mission_tags;
+pub mod mission_templates;
+pub mod mission_waitlist;
 pub mod missions;
+pub mod notifications;
+pub mod point_ledger;
+pub mod rewards;
+pub mod seasons;
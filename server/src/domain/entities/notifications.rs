@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::notifications;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = notifications)]
+pub struct NotificationEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub mission_id: Option<i32>,
+    pub kind: String,
+    pub message: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = notifications)]
+pub struct AddNotificationEntity {
+    pub brawler_id: i32,
+    pub mission_id: Option<i32>,
+    pub kind: String,
+    pub message: String,
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::point_ledger;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = point_ledger)]
+pub struct PointLedgerEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub points: i32,
+    pub created_at: NaiveDateTime,
+    pub reason: String,
+    pub mission_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = point_ledger)]
+pub struct AddPointLedgerEntity {
+    pub brawler_id: i32,
+    pub points: i32,
+    pub reason: String,
+    pub mission_id: Option<i32>,
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::{reward_redemptions, rewards};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = rewards)]
+pub struct RewardEntity {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub value: String,
+    pub cost: i32,
+    pub stock: Option<i32>,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = reward_redemptions)]
+pub struct RewardRedemptionEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub reward_id: i32,
+    pub cost: i32,
+    pub redeemed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = reward_redemptions)]
+pub struct AddRewardRedemptionEntity {
+    pub brawler_id: i32,
+    pub reward_id: i32,
+    pub cost: i32,
+}
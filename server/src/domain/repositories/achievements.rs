@@ -0,0 +1,21 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::achievements::AchievementEntity,
+    value_objects::achievement_model::{
+        AchievementCatalogModel, BrawlerStatsModel, UnlockedAchievementModel,
+    },
+};
+
+#[automock]
+#[async_trait]
+pub trait AchievementRepository {
+    async fn get_all(&self) -> Result<Vec<AchievementEntity>>;
+    async fn get_catalog(&self) -> Result<Vec<AchievementCatalogModel>>;
+    async fn get_unlocked(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievementModel>>;
+    async fn get_unlocked_ids(&self, brawler_id: i32) -> Result<Vec<i32>>;
+    async fn get_brawler_stats(&self, brawler_id: i32) -> Result<BrawlerStatsModel>;
+    async fn unlock(&self, brawler_id: i32, achievement_ids: Vec<i32>) -> Result<()>;
+}
@@ -1,24 +1,43 @@
 use crate::{
     domain::{
         entities::{
-            brawlers::{Brawler, BrawlerEntity, RegisterBrawlerEntity}, 
-            missions::MissionEntity
+            brawler_level_ups::{AddBrawlerLevelUpEntity, BrawlerLevelUpEntity},
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity}, 
+            missions::MissionEntity,
+            point_ledger::{AddPointLedgerEntity, PointLedgerEntity},
         }, 
-        value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage}
+        value_objects::{
+            base64_image::Base64Image, leaderboard_model::LeaderboardRowModel,
+            point_reasons::PointReasons, streak_model::StreakModel, uploaded_image::UploadedImage,
+        }
     }, 
     infrastructure::cloudinary::UploadImageOptions
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait BrawlerRepository {
     async fn crew_counting(&self, mission_id: i32) -> Result<i32>;
     async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionEntity>>;
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity>;
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity>;
     async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity>;
-    async fn get_leaderboard(&self) -> Result<Vec<Brawler>, String>; 
+    async fn get_leaderboard(
+        &self,
+        window_start: Option<NaiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LeaderboardRowModel>>;
+    // Every brawler has a leaderboard row, whatever the window
+    async fn count_leaderboard(&self) -> Result<i64>;
+    async fn get_leaderboard_around(
+        &self,
+        brawler_id: i32,
+        window_start: Option<NaiveDateTime>,
+        around: i64,
+    ) -> Result<Vec<LeaderboardRowModel>>;
     async fn upload_avatar(
         &self,
         brawler_id: i32,
@@ -26,5 +45,22 @@ pub trait BrawlerRepository {
         option: UploadImageOptions,
     ) -> Result<UploadedImage>;
     async fn update_profile(&self, brawler_id: i32, update_model: crate::domain::value_objects::brawler_model::UpdateBrawlerModel) -> Result<BrawlerEntity>;
-    async fn add_points(&self, brawler_id: i32, points: i32) -> Result<()>;
+    async fn add_points(&self, entry: AddPointLedgerEntity) -> Result<i32>;
+    async fn has_point_entry(
+        &self,
+        brawler_id: i32,
+        mission_id: i32,
+        reason: PointReasons,
+    ) -> Result<bool>;
+    async fn get_point_history(
+        &self,
+        brawler_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PointLedgerEntity>>;
+    async fn count_point_history(&self, brawler_id: i32) -> Result<i64>;
+    async fn update_streak(&self, brawler_id: i32, streak: StreakModel) -> Result<()>;
+    async fn record_level_up(&self, level_up: AddBrawlerLevelUpEntity) -> Result<i32>;
+    async fn get_level_ups(&self, brawler_id: i32, unseen_only: bool) -> Result<Vec<BrawlerLevelUpEntity>>;
+    async fn mark_level_ups_seen(&self, brawler_id: i32) -> Result<usize>;
 }
\ No newline at end of file
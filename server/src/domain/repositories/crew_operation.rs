@@ -1,10 +1,63 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
-use crate::domain::entities::crew_memberships::CrewMemberShips;
+use crate::domain::{
+    entities::{
+        crew_memberships::CrewMemberShips,
+        join_requests::{AddJoinRequestEntity, JoinRequestEntity},
+        mission_bans::AddMissionBanEntity,
+        mission_waitlist::MissionWaitlistEntity,
+    },
+    value_objects::{
+        join_request_statuses::{JoinOutcomes, JoinRequestStatuses},
+        lanes::Lanes,
+    },
+};
 
+#[automock]
 #[async_trait]
 pub trait CrewOperationRepository {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    async fn join(&self, crew_member_ships: CrewMemberShips, crew_capacity: i32) -> Result<()>;
+    /// Joins like `join`, but queues the brawler on the waitlist instead of failing when full.
+    /// A claimed `lane` must be declared by the mission and still have a free slot.
+    async fn join_or_waitlist(
+        &self,
+        crew_member_ships: CrewMemberShips,
+        lane: Option<Lanes>,
+        crew_capacity: i32,
+    ) -> Result<JoinOutcomes>;
+    /// Moves a crew member to `lane`, or clears their lane with `None`.
+    async fn claim_lane(&self, crew_member_ships: CrewMemberShips, lane: Option<Lanes>) -> Result<()>;
     async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    /// The mission's waitlist, first come first served.
+    async fn get_waitlist(&self, mission_id: i32) -> Result<Vec<MissionWaitlistEntity>>;
+    async fn leave_waitlist(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    /// Removes the brawler from the crew and bans them from rejoining, in one transaction.
+    async fn kick(&self, add_mission_ban_entity: AddMissionBanEntity) -> Result<()>;
+    async fn is_banned(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
+    /// Hands the mission to a crew member, who leaves the crew while the old chief joins it
+    /// without a lane. Returns the lane the new chief gave up, if they had claimed one.
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        new_chief_id: i32,
+    ) -> Result<Option<Lanes>>;
+    async fn add_join_request(&self, add_join_request_entity: AddJoinRequestEntity) -> Result<i32>;
+    async fn get_join_request(&self, request_id: i32) -> Result<JoinRequestEntity>;
+    async fn get_join_requests_by_mission(
+        &self,
+        mission_id: i32,
+        status: JoinRequestStatuses,
+    ) -> Result<Vec<JoinRequestEntity>>;
+    async fn get_join_requests_by_brawler(
+        &self,
+        brawler_id: i32,
+        status: JoinRequestStatuses,
+    ) -> Result<Vec<JoinRequestEntity>>;
+    /// Adds the requester to the crew and marks the request accepted in one transaction.
+    async fn accept_join_request(&self, request_id: i32, crew_capacity: i32) -> Result<()>;
+    /// Moves a pending request to `status`; fails when it was already decided.
+    async fn close_join_request(&self, request_id: i32, status: JoinRequestStatuses) -> Result<()>;
 }
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_invitations::{AddMissionInvitationEntity, MissionInvitationEntity},
+    value_objects::mission_invitation_model::{InvitationStatuses, MissionInvitationModel},
+};
+
+#[async_trait]
+pub trait MissionInvitationRepository {
+    async fn add(&self, add_mission_invitation_entity: AddMissionInvitationEntity) -> Result<i32>;
+    async fn get_one(&self, invitation_id: i32) -> Result<MissionInvitationEntity>;
+    async fn get_by_brawler(
+        &self,
+        brawler_id: i32,
+        status: InvitationStatuses,
+    ) -> Result<Vec<MissionInvitationModel>>;
+    async fn get_by_mission(
+        &self,
+        mission_id: i32,
+        status: InvitationStatuses,
+    ) -> Result<Vec<MissionInvitationModel>>;
+    /// Adds the invitee to the crew and marks the invitation accepted in one transaction.
+    async fn accept(&self, invitation_id: i32, crew_capacity: i32) -> Result<()>;
+    /// Moves a pending invitation to `status`; fails when it was already decided.
+    async fn close(&self, invitation_id: i32, status: InvitationStatuses) -> Result<()>;
+    /// Marks pending invitations that expired at or before `expired_before` as Expired.
+    /// Returns how many changed.
+    async fn expire_lapsed(&self, expired_before: NaiveDateTime) -> Result<usize>;
+}
@@ -1,11 +1,32 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
 
-use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
+use crate::domain::{
+    entities::missions::{AddMissionEntity, EditMissionEntity},
+    value_objects::mission_lane_model::LaneSlotModel,
+};
 
+#[automock]
 #[async_trait]
 pub trait MissionManagementRepository {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
+    async fn add(
+        &self,
+        add_mission_entity: AddMissionEntity,
+        tags: Vec<String>,
+        lanes: Vec<LaneSlotModel>,
+    ) -> Result<i32>;
+    /// Only edits the mission when `chief_id` leads it. `tags` and `lanes` replace the
+    /// mission's tag set and declared lanes when given.
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: EditMissionEntity,
+        tags: Option<Vec<String>>,
+        lanes: Option<Vec<LaneSlotModel>>,
+    ) -> Result<i32>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    async fn archive_finished(&self, finished_before: NaiveDateTime) -> Result<usize>;
 }
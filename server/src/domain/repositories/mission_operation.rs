@@ -1,9 +1,19 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::missions::MissionEntity, value_objects::mission_transition::MissionTransitionModel,
+};
 
 #[async_trait]
 pub trait MissionOperationRepository {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    async fn transition(&self, mission_id: i32, transition: MissionTransitionModel) -> Result<i32>;
+    async fn claim_due_reminders(
+        &self,
+        now: NaiveDateTime,
+        remind_before: NaiveDateTime,
+    ) -> Result<Vec<MissionEntity>>;
+    // Undoes a claim whose reminder could not be sent, so the next sweep retries it
+    async fn release_due_reminder(&self, mission_id: i32) -> Result<()>;
 }
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+
+use crate::domain::entities::{
+    mission_series::{AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity},
+    missions::AddMissionEntity,
+};
+
+#[automock]
+#[async_trait]
+pub trait MissionSeriesRepository {
+    async fn add(&self, add_mission_series_entity: AddMissionSeriesEntity) -> Result<i32>;
+    async fn get_one(&self, series_id: i32) -> Result<MissionSeriesEntity>;
+    async fn get_by_chief(&self, chief_id: i32) -> Result<Vec<MissionSeriesEntity>>;
+    async fn edit(
+        &self,
+        series_id: i32,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+    ) -> Result<i32>;
+    async fn stop(&self, series_id: i32) -> Result<()>;
+    async fn get_due(&self, today: NaiveDate) -> Result<Vec<MissionSeriesEntity>>;
+    /// Moves the series past `scheduled_on` without creating an occurrence.
+    /// Returns false when another sweep already moved it.
+    async fn advance(
+        &self,
+        series_id: i32,
+        scheduled_on: NaiveDate,
+        next_occurrence_on: Option<NaiveDate>,
+    ) -> Result<bool>;
+    /// Creates the mission for `occurrence_on` and moves the series past `scheduled_on` in one
+    /// transaction. Returns None when another sweep already moved it.
+    async fn create_occurrence(
+        &self,
+        series_id: i32,
+        scheduled_on: NaiveDate,
+        occurrence_on: NaiveDate,
+        next_occurrence_on: Option<NaiveDate>,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<Option<i32>>;
+}
@@ -0,0 +1,30 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_templates::{
+        AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+    },
+    value_objects::mission_template_model::TemplateScopes,
+};
+
+#[async_trait]
+pub trait MissionTemplateRepository {
+    async fn add(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<i32>;
+    async fn get_one(&self, template_id: i32) -> Result<MissionTemplateEntity>;
+    async fn get_visible(
+        &self,
+        brawler_id: i32,
+        scope: Option<TemplateScopes>,
+    ) -> Result<Vec<MissionTemplateEntity>>;
+    async fn count_owned(&self, owner_id: i32) -> Result<i64>;
+    async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        edit_mission_template_entity: EditMissionTemplateEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()>;
+    /// Inserts or replaces a global template by name, returning true when it was new.
+    async fn upsert_global(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<bool>;
+}
@@ -1,10 +1,18 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
 
 use crate::domain::{
-    entities::missions::MissionEntity, value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+    entities::{mission_status_history::MissionStatusHistoryEntity, missions::MissionEntity},
+    value_objects::{
+        brawler_model::BrawlerModel, mission_filter::MissionFilter,
+        mission_lane_model::MissionLaneModel, mission_model::MissionSnippetModel,
+        mission_tag_model::TagCountModel,
+    },
 };
 
+#[automock]
 #[async_trait]
 pub trait MissionViewingRepository {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
@@ -12,9 +20,25 @@ pub trait MissionViewingRepository {
     async fn get_all(
         &self,
         mission_filter: &MissionFilter,
+        limit: i64,
+        offset: i64,
     ) -> Result<Vec<(MissionEntity, i64)>>;
+    async fn count_all(&self, mission_filter: &MissionFilter) -> Result<i64>;
     async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
     async fn get_daily_interaction_count(&self, brawler_id: i32) -> Result<i64>;
     async fn get_daily_earned_points(&self, brawler_id: i32) -> Result<i64>;
+    async fn get_total_points(&self, brawler_id: i32) -> Result<i32>;
     async fn get_crew_ids(&self, mission_id: i32) -> Result<Vec<i32>>;
+    async fn get_overdue(&self, due_before: NaiveDateTime) -> Result<Vec<MissionEntity>>;
+    async fn get_status_history(&self, mission_id: i32) -> Result<Vec<MissionStatusHistoryEntity>>;
+    /// (mission_id, tag name) pairs for the given missions.
+    async fn get_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>>;
+    async fn get_popular_tags(&self, limit: i64) -> Result<Vec<TagCountModel>>;
+    /// Declared lanes of the given missions with how many crew claimed each.
+    async fn get_lanes(&self, mission_ids: &[i32]) -> Result<Vec<MissionLaneModel>>;
+    async fn get_search_snippets(
+        &self,
+        mission_ids: &[i32],
+        search_query: &str,
+    ) -> Result<Vec<MissionSnippetModel>>;
 }
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::notifications::{AddNotificationEntity, NotificationEntity};
+
+#[automock]
+#[async_trait]
+pub trait NotificationRepository {
+    async fn add_many(&self, notifications: Vec<AddNotificationEntity>) -> Result<usize>;
+    async fn get_by_brawler(
+        &self,
+        brawler_id: i32,
+        unread_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn mark_read(&self, brawler_id: i32, notification_id: Option<i32>) -> Result<usize>;
+}
@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_ratings::MissionMvpEntity,
+    value_objects::points_recompute_model::{
+        BrawlerPointsModel, LedgerPointsModel, MissionParticipationModel, PointsDiffModel,
+    },
+};
+
+#[async_trait]
+pub trait PointsRecomputeRepository {
+    async fn get_brawler_points(&self) -> Result<Vec<BrawlerPointsModel>>;
+    async fn get_participations(&self) -> Result<Vec<MissionParticipationModel>>;
+    async fn get_mvps(&self) -> Result<Vec<MissionMvpEntity>>;
+    async fn get_other_ledger_points(&self) -> Result<Vec<LedgerPointsModel>>;
+    async fn apply(&self, diffs: Vec<PointsDiffModel>) -> Result<()>;
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_ratings::{
+        AddMissionMvpEntity, AddMissionRatingEntity, AddMvpVoteEntity, MissionMvpEntity,
+        MissionRatingEntity,
+    },
+    value_objects::rating_model::{MvpTallyModel, RatingAggregateModel, RatingTagCountModel},
+};
+
+#[async_trait]
+pub trait RatingRepository {
+    async fn upsert_rating(&self, add_rating_entity: AddMissionRatingEntity) -> Result<i32>;
+    async fn get_ratings_by_rater(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+    ) -> Result<Vec<MissionRatingEntity>>;
+    async fn upsert_mvp_vote(&self, add_vote_entity: AddMvpVoteEntity) -> Result<()>;
+    async fn get_mvp_vote(&self, mission_id: i32, voter_id: i32) -> Result<Option<i32>>;
+    async fn get_mvp_tally(&self, mission_id: i32) -> Result<Vec<MvpTallyModel>>;
+    async fn get_mvp(&self, mission_id: i32) -> Result<Option<MissionMvpEntity>>;
+    async fn get_unsettled_mvp_missions(&self, completed_before: NaiveDateTime) -> Result<Vec<i32>>;
+    async fn award_mvp(&self, add_mvp_entity: AddMissionMvpEntity) -> Result<bool>;
+    async fn get_aggregate(&self, brawler_id: i32) -> Result<RatingAggregateModel>;
+    async fn get_tag_counts(&self, brawler_id: i32) -> Result<Vec<RatingTagCountModel>>;
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::rewards::RewardEntity,
+    value_objects::reward_model::{OwnedRewardModel, RedemptionReceiptModel},
+};
+
+#[async_trait]
+pub trait RewardRepository {
+    async fn get_catalog(&self, kind: Option<String>) -> Result<Vec<RewardEntity>>;
+    async fn get_owned(&self, brawler_id: i32) -> Result<Vec<OwnedRewardModel>>;
+    async fn redeem(&self, brawler_id: i32, reward_id: i32) -> Result<RedemptionReceiptModel>;
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::seasons::{AddSeasonEntity, SeasonEntity},
+    value_objects::season_model::SeasonStandingModel,
+};
+
+#[async_trait]
+pub trait SeasonRepository {
+    async fn get_all(&self) -> Result<Vec<SeasonEntity>>;
+    async fn get_one(&self, season_id: i32) -> Result<SeasonEntity>;
+    async fn get_current(&self) -> Result<Option<SeasonEntity>>;
+    async fn get_latest(&self) -> Result<Option<SeasonEntity>>;
+    async fn get_ended_unarchived(&self) -> Result<Vec<SeasonEntity>>;
+    async fn add(&self, add_season_entity: AddSeasonEntity) -> Result<i32>;
+    async fn get_live_standings(&self, season_id: i32, limit: i64) -> Result<Vec<SeasonStandingModel>>;
+    async fn get_archived_standings(&self, season_id: i32, limit: i64) -> Result<Vec<SeasonStandingModel>>;
+    async fn archive(&self, season_id: i32) -> Result<usize>;
+}
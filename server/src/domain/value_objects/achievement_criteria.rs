@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AchievementCriteria {
+    MissionsJoined,
+    MissionsCompleted,
+    ChiefMissionsCompleted,
+    HardMissionsCompleted,
+    DailyStreak,
+}
+
+impl Display for AchievementCriteria {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AchievementCriteria::MissionsJoined => write!(f, "MissionsJoined"),
+            AchievementCriteria::MissionsCompleted => write!(f, "MissionsCompleted"),
+            AchievementCriteria::ChiefMissionsCompleted => write!(f, "ChiefMissionsCompleted"),
+            AchievementCriteria::HardMissionsCompleted => write!(f, "HardMissionsCompleted"),
+            AchievementCriteria::DailyStreak => write!(f, "DailyStreak"),
+        }
+    }
+}
+
+impl AchievementCriteria {
+    pub fn try_from_str(criteria: &str) -> Result<Self> {
+        match criteria {
+            "MissionsJoined" => Ok(Self::MissionsJoined),
+            "MissionsCompleted" => Ok(Self::MissionsCompleted),
+            "ChiefMissionsCompleted" => Ok(Self::ChiefMissionsCompleted),
+            "HardMissionsCompleted" => Ok(Self::HardMissionsCompleted),
+            "DailyStreak" => Ok(Self::DailyStreak),
+            _ => Err(anyhow::anyhow!("Invalid achievement criteria: {}", criteria)),
+        }
+    }
+}
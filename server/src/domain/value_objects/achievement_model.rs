@@ -0,0 +1,61 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::{Queryable, QueryableByName},
+    sql_types::{BigInt, Double, Integer, Text, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::achievement_criteria::AchievementCriteria;
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct AchievementCatalogModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub code: String,
+    #[diesel(sql_type = Varchar)]
+    pub name: String,
+    #[diesel(sql_type = Text)]
+    pub description: String,
+    #[diesel(sql_type = Varchar)]
+    pub icon: String,
+    #[diesel(sql_type = BigInt)]
+    pub unlocked_count: i64,
+    #[diesel(sql_type = Double)]
+    pub unlock_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable)]
+pub struct UnlockedAchievementModel {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub icon: String,
+    pub unlocked_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerStatsModel {
+    #[diesel(sql_type = BigInt)]
+    pub missions_joined: i64,
+    #[diesel(sql_type = BigInt)]
+    pub missions_completed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub chief_missions_completed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub hard_missions_completed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub longest_streak: i64,
+}
+
+impl BrawlerStatsModel {
+    pub fn value_of(&self, criteria: &AchievementCriteria) -> i64 {
+        match criteria {
+            AchievementCriteria::MissionsJoined => self.missions_joined,
+            AchievementCriteria::MissionsCompleted => self.missions_completed,
+            AchievementCriteria::ChiefMissionsCompleted => self.chief_missions_completed,
+            AchievementCriteria::HardMissionsCompleted => self.hard_missions_completed,
+            AchievementCriteria::DailyStreak => self.longest_streak,
+        }
+    }
+}
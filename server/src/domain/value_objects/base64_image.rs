@@ -24,11 +24,9 @@ impl Base64Image {
             Err(_) => return Err(anyhow::anyhow!("Invalid base64 image data.")),
         };
         let file_type = match infer::get(&bytes) {
-            Some(t) => if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" {
+            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
                 t.mime_type()
-            } else {
-                return Err(anyhow::anyhow!("Invalid base64 image data."));
-            },
+            }
             _ => return Err(anyhow::anyhow!("Invalid base64 image data.")),
         };
 
@@ -1,7 +1,10 @@
-use diesel::{prelude::QueryableByName, sql_types::{BigInt, Varchar, Integer}}; 
+use diesel::{prelude::QueryableByName, sql_types::{BigInt, Double, Integer, Nullable, Varchar}}; 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::{
+    domain::{entities::brawlers::RegisterBrawlerEntity, value_objects::lanes::Lanes},
+    infrastructure::database::schema::sql_types::Lane,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -30,10 +33,19 @@ pub struct BrawlerModel {
     pub avatar_url: String,
     #[diesel(sql_type = BigInt)]
     pub mission_success_count: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub rating_average: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mvp_count: i64,
     #[diesel(sql_type = BigInt)]
     pub mission_joined_count: i64,
     #[diesel(sql_type = Integer)]  
     pub total_points: i32,         
+    // Lane claimed in the mission being viewed
+    #[diesel(sql_type = Nullable<Lane>)]
+    pub lane: Option<Lanes>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+pub const MAX_KICK_REASON_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickCrewModel {
+    pub brawler_id: i32,
+    // Shown to the removed brawler in their notification
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferChiefModel {
+    pub brawler_id: i32,
+}
@@ -0,0 +1,71 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::sql_types::JoinRequestStatus;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = JoinRequestStatus)]
+pub enum JoinRequestStatuses {
+    Pending,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+impl Display for JoinRequestStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinRequestStatuses::Pending => write!(f, "Pending"),
+            JoinRequestStatuses::Accepted => write!(f, "Accepted"),
+            JoinRequestStatuses::Rejected => write!(f, "Rejected"),
+            JoinRequestStatuses::Withdrawn => write!(f, "Withdrawn"),
+        }
+    }
+}
+
+impl JoinRequestStatuses {
+    pub const ALL: [JoinRequestStatuses; 4] = [
+        JoinRequestStatuses::Pending,
+        JoinRequestStatuses::Accepted,
+        JoinRequestStatuses::Rejected,
+        JoinRequestStatuses::Withdrawn,
+    ];
+
+    pub fn try_from_str(status: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.to_string() == status)
+            .ok_or_else(|| anyhow::anyhow!("Invalid join request status: {}", status))
+    }
+}
+
+impl ToSql<JoinRequestStatus, Pg> for JoinRequestStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<JoinRequestStatus, Pg> for JoinRequestStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(status)?)
+    }
+}
+
+/// What a join call did: the brawler is now crew, the chief has to approve first,
+/// or the mission was full and the brawler was queued.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum JoinOutcomes {
+    Joined,
+    Requested { request_id: i32 },
+    Waitlisted { position: i64 },
+}
@@ -0,0 +1,69 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::sql_types::Lane;
+
+/// RoV lanes a crew member can cover, in the same order as the lane enum.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, AsExpression,
+    FromSqlRow,
+)]
+#[diesel(sql_type = Lane)]
+pub enum Lanes {
+    Slayer,
+    Mid,
+    Jungle,
+    AbyssalDragon,
+    Roaming,
+}
+
+impl Display for Lanes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lanes::Slayer => write!(f, "Slayer"),
+            Lanes::Mid => write!(f, "Mid"),
+            Lanes::Jungle => write!(f, "Jungle"),
+            Lanes::AbyssalDragon => write!(f, "AbyssalDragon"),
+            Lanes::Roaming => write!(f, "Roaming"),
+        }
+    }
+}
+
+impl Lanes {
+    pub const ALL: [Lanes; 5] = [
+        Lanes::Slayer,
+        Lanes::Mid,
+        Lanes::Jungle,
+        Lanes::AbyssalDragon,
+        Lanes::Roaming,
+    ];
+
+    pub fn try_from_str(lane: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|l| l.to_string() == lane)
+            .ok_or_else(|| anyhow::anyhow!("Invalid lane: {}", lane))
+    }
+}
+
+impl ToSql<Lane, Pg> for Lanes {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Lane, Pg> for Lanes {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let lane = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(lane)?)
+    }
+}
@@ -0,0 +1,53 @@
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{
+    leaderboard_window::LeaderboardWindow, tier_progress_model::TierProgressModel,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LeaderboardFilter {
+    pub window: Option<LeaderboardWindow>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset_token: Option<String>,
+    // how many brawlers to show above and below the caller on /leaderboard/me
+    pub around: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct LeaderboardRowModel {
+    #[diesel(sql_type = BigInt)]
+    pub rank: i64,
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub username: String,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = Integer)]
+    pub total_points: i32,
+    // points earned inside the requested window
+    #[diesel(sql_type = BigInt)]
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntryModel {
+    #[serde(flatten)]
+    pub brawler: LeaderboardRowModel,
+    pub tier: TierProgressModel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardMeModel {
+    pub window: LeaderboardWindow,
+    pub me: LeaderboardEntryModel,
+    pub above: Vec<LeaderboardEntryModel>,
+    pub below: Vec<LeaderboardEntryModel>,
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use chrono::{Datelike, Days, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardWindow {
+    Daily,
+    Weekly,
+    Monthly,
+    #[default]
+    All,
+}
+
+impl LeaderboardWindow {
+    /// Start of the window (UTC), `None` means lifetime `total_points`.
+    pub fn starts_at(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let today = now.date();
+        let start = match self {
+            LeaderboardWindow::Daily => today,
+            LeaderboardWindow::Weekly => {
+                today.checked_sub_days(Days::new(today.weekday().num_days_from_monday() as u64))?
+            }
+            LeaderboardWindow::Monthly => today.with_day(1)?,
+            LeaderboardWindow::All => return None,
+        };
+        start.and_hms_opt(0, 0, 0)
+    }
+}
+
+impl Display for LeaderboardWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardWindow::Daily => write!(f, "daily"),
+            LeaderboardWindow::Weekly => write!(f, "weekly"),
+            LeaderboardWindow::Monthly => write!(f, "monthly"),
+            LeaderboardWindow::All => write!(f, "all"),
+        }
+    }
+}
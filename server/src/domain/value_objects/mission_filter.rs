@@ -1,12 +1,245 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::value_objects::{
+    mission_model::normalize_difficulty,
+    mission_sorts::{MissionSorts, SortDirections},
+    mission_statuses::MissionStatuses,
+    mission_tag_model::{TagMatches, normalize_tag},
+    rank_tiers::RankTiers,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
     pub name: Option<String>,
+    // Full-text search over name and description, ordered by relevance
+    pub q: Option<String>,
     pub status: Option<MissionStatuses>,
+    // Comma separated, e.g. ?statuses=Open,InProgress
+    pub statuses: Option<String>,
+    // Comma separated EASY, NORMAL or HARD
+    pub difficulty: Option<String>,
+    pub due_after: Option<NaiveDateTime>,
+    pub due_before: Option<NaiveDateTime>,
+    // Joinable (Open or Failed) missions that still have room in the crew
+    pub has_open_slots: Option<bool>,
+    // Missions whose crew isn't full, whatever their status
+    pub not_full: Option<bool>,
+    // Missions the caller has joined as crew; needs a signed-in caller
+    pub member: Option<bool>,
+    // Missions whose rank requirement the caller meets; needs a signed-in caller
+    pub eligible: Option<bool>,
+    pub sort_by: Option<MissionSorts>,
+    pub sort_dir: Option<SortDirections>,
     pub chief_id: Option<i32>,
+    pub series_id: Option<i32>,
+    // Comma separated, e.g. ?tags=ranked,scrim
+    pub tags: Option<String>,
+    pub tag_match: Option<TagMatches>,
+    // Archived missions are left out unless asked for
+    pub include_archived: Option<bool>,
     pub page: Option<i64>,
     pub limit: Option<i64>,
+    pub offset_token: Option<String>,
+    // Set from the access token, never from the query string
+    #[serde(skip)]
+    pub viewer_id: Option<i32>,
+    // Resolved from the caller's total_points when `eligible` is asked for
+    #[serde(skip)]
+    pub viewer_rank: Option<RankTiers>,
+    #[serde(skip)]
+    pub viewer_total_points: Option<i32>,
+    // MAX_CREW_PER_MISSION; a crew is full at whichever is lower of this and max_crew
+    #[serde(skip)]
+    pub crew_ceiling: Option<i32>,
+}
+
+impl MissionFilter {
+    pub fn validate(&self) -> Result<()> {
+        self.statuses()?;
+        self.difficulties()?;
+        self.tag_names()?;
+
+        if let (Some(due_after), Some(due_before)) = (self.due_after, self.due_before)
+            && due_after > due_before
+        {
+            return Err(anyhow::anyhow!("due_after must not be later than due_before"));
+        }
+        if self.member.unwrap_or(false) && self.viewer_id.is_none() {
+            return Err(anyhow::anyhow!(
+                "Sign in to filter the missions you are a member of"
+            ));
+        }
+        if self.eligible.unwrap_or(false) && self.viewer_id.is_none() {
+            return Err(anyhow::anyhow!(
+                "Sign in to filter the missions you are eligible for"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// `status` and `statuses` together; empty when neither was given.
+    pub fn statuses(&self) -> Result<Vec<MissionStatuses>> {
+        let mut statuses: Vec<MissionStatuses> = self.status.into_iter().collect();
+        for status in self
+            .statuses
+            .iter()
+            .flat_map(|statuses| statuses.split(','))
+            .map(str::trim)
+            .filter(|status| !status.is_empty())
+        {
+            let status = MissionStatuses::try_from_str(status)?;
+            if !statuses.contains(&status) {
+                statuses.push(status);
+            }
+        }
+        Ok(statuses)
+    }
+
+    /// The requested difficulties in their stored form.
+    pub fn difficulties(&self) -> Result<Vec<String>> {
+        let mut difficulties: Vec<String> = Vec::new();
+        for difficulty in self
+            .difficulty
+            .iter()
+            .flat_map(|difficulties| difficulties.split(','))
+            .map(str::trim)
+            .filter(|difficulty| !difficulty.is_empty())
+        {
+            let difficulty = normalize_difficulty(difficulty)?;
+            if !difficulties.contains(&difficulty) {
+                difficulties.push(difficulty);
+            }
+        }
+        Ok(difficulties)
+    }
+
+    pub fn search_query(&self) -> Option<&str> {
+        self.q.as_deref().map(str::trim).filter(|q| !q.is_empty())
+    }
+
+    /// The requested tags in their stored form. Fails on a name that can never be a tag, so a
+    /// typo doesn't silently turn the tag filter off.
+    pub fn tag_names(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = Vec::new();
+        for name in self
+            .tags
+            .iter()
+            .flat_map(|tags| tags.split(','))
+            .filter(|name| !name.trim().is_empty())
+        {
+            let name = normalize_tag(name)?;
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn empty_filter_is_valid() {
+        assert!(MissionFilter::default().validate().is_ok());
+    }
+
+    #[test]
+    fn merges_status_and_statuses_without_duplicates() {
+        let filter = MissionFilter {
+            status: Some(MissionStatuses::Open),
+            statuses: Some("InProgress, Open,,".to_string()),
+            ..Default::default()
+        };
+
+        assert!(filter.validate().is_ok());
+        assert_eq!(
+            filter.statuses().unwrap(),
+            vec![MissionStatuses::Open, MissionStatuses::InProgress]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_statuses_and_difficulties() {
+        let bad_status = MissionFilter {
+            statuses: Some("Open,Done".to_string()),
+            ..Default::default()
+        };
+        let bad_difficulty = MissionFilter {
+            difficulty: Some("EASY,EXTREME".to_string()),
+            ..Default::default()
+        };
+
+        assert!(bad_status.validate().is_err());
+        assert!(bad_difficulty.validate().is_err());
+    }
+
+    #[test]
+    fn normalizes_difficulties_without_duplicates() {
+        let filter = MissionFilter {
+            difficulty: Some(" hard,easy, HARD,,".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(filter.difficulties().unwrap(), vec!["HARD", "EASY"]);
+    }
+
+    #[test]
+    fn rejects_tags_that_can_never_match() {
+        let filter = MissionFilter {
+            tags: Some("ranked,!".to_string()),
+            ..Default::default()
+        };
+
+        assert!(filter.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_an_inverted_due_range() {
+        let inverted = MissionFilter {
+            due_after: Some(at(2)),
+            due_before: Some(at(1)),
+            ..Default::default()
+        };
+        let same_instant = MissionFilter {
+            due_after: Some(at(1)),
+            due_before: Some(at(1)),
+            ..Default::default()
+        };
+
+        assert!(inverted.validate().is_err());
+        assert!(same_instant.validate().is_ok());
+    }
+
+    #[test]
+    fn member_and_eligible_need_a_viewer() {
+        let member = MissionFilter {
+            member: Some(true),
+            ..Default::default()
+        };
+        let eligible = MissionFilter {
+            eligible: Some(true),
+            ..Default::default()
+        };
+
+        for filter in [member, eligible] {
+            assert!(filter.validate().is_err());
+
+            let signed_in = MissionFilter {
+                viewer_id: Some(1),
+                ..filter
+            };
+            assert!(signed_in.validate().is_ok());
+        }
+    }
 }
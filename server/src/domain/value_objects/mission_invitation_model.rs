@@ -0,0 +1,86 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::sql_types::InvitationStatus;
+
+pub const DEFAULT_INVITATION_HOURS: i64 = 48;
+pub const MAX_INVITATION_HOURS: i64 = 168;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = InvitationStatus)]
+pub enum InvitationStatuses {
+    Pending,
+    Accepted,
+    Declined,
+    Expired,
+}
+
+impl Display for InvitationStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvitationStatuses::Pending => write!(f, "Pending"),
+            InvitationStatuses::Accepted => write!(f, "Accepted"),
+            InvitationStatuses::Declined => write!(f, "Declined"),
+            InvitationStatuses::Expired => write!(f, "Expired"),
+        }
+    }
+}
+
+impl InvitationStatuses {
+    pub const ALL: [InvitationStatuses; 4] = [
+        InvitationStatuses::Pending,
+        InvitationStatuses::Accepted,
+        InvitationStatuses::Declined,
+        InvitationStatuses::Expired,
+    ];
+
+    pub fn try_from_str(status: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.to_string() == status)
+            .ok_or_else(|| anyhow::anyhow!("Invalid invitation status: {}", status))
+    }
+}
+
+impl ToSql<InvitationStatus, Pg> for InvitationStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<InvitationStatus, Pg> for InvitationStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(status)?)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteBrawlerModel {
+    pub username: String,
+    // Defaults to DEFAULT_INVITATION_HOURS
+    pub expires_in_hours: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionInvitationModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub mission_name: String,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+    pub status: InvitationStatuses,
+    pub expires_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
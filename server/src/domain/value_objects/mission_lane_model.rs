@@ -0,0 +1,116 @@
+use anyhow::Result;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::value_objects::lanes::Lanes, infrastructure::database::schema::sql_types::Lane,
+};
+
+/// A lane the chief wants covered and how many crew it takes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaneSlotModel {
+    pub lane: Lanes,
+    pub slots: i32,
+}
+
+/// Declared lane with how many crew members have claimed it so far.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionLaneModel {
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Lane)]
+    pub lane: Lanes,
+    #[diesel(sql_type = Integer)]
+    pub slots: i32,
+    #[diesel(sql_type = BigInt)]
+    pub filled: i64,
+}
+
+/// Rejects empty or duplicate lanes and lane slots that add up to more than `max_crew`.
+/// Returns the lanes in canonical lane order.
+pub fn validate_lanes(lanes: &[LaneSlotModel], max_crew: i32) -> Result<Vec<LaneSlotModel>> {
+    let mut validated = lanes.to_vec();
+    validated.sort_by_key(|lane_slot| lane_slot.lane);
+
+    for (index, lane_slot) in validated.iter().enumerate() {
+        if lane_slot.slots < 1 {
+            return Err(anyhow::anyhow!(
+                "{} lane needs at least 1 slot",
+                lane_slot.lane
+            ));
+        }
+        if lane_slot.slots > max_crew {
+            return Err(anyhow::anyhow!(
+                "{} lane cannot have more slots than max_crew ({})",
+                lane_slot.lane,
+                max_crew
+            ));
+        }
+        if index > 0 && validated[index - 1].lane == lane_slot.lane {
+            return Err(anyhow::anyhow!("{} lane is listed more than once", lane_slot.lane));
+        }
+    }
+
+    let total_slots: i64 = validated.iter().map(|lane_slot| lane_slot.slots as i64).sum();
+    if total_slots > max_crew as i64 {
+        return Err(anyhow::anyhow!(
+            "Lane slots ({}) cannot exceed max_crew ({})",
+            total_slots,
+            max_crew
+        ));
+    }
+
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(lane: Lanes, slots: i32) -> LaneSlotModel {
+        LaneSlotModel { lane, slots }
+    }
+
+    #[test]
+    fn returns_lanes_in_canonical_order() {
+        let lanes = [slot(Lanes::Roaming, 1), slot(Lanes::Slayer, 2), slot(Lanes::Mid, 1)];
+
+        assert_eq!(
+            validate_lanes(&lanes, 5).unwrap(),
+            vec![slot(Lanes::Slayer, 2), slot(Lanes::Mid, 1), slot(Lanes::Roaming, 1)]
+        );
+    }
+
+    #[test]
+    fn no_lanes_is_valid() {
+        assert_eq!(validate_lanes(&[], 5).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn rejects_lanes_without_slots() {
+        assert!(validate_lanes(&[slot(Lanes::Mid, 0)], 5).is_err());
+        assert!(validate_lanes(&[slot(Lanes::Mid, -1)], 5).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_lanes() {
+        assert!(validate_lanes(&[slot(Lanes::Jungle, 1), slot(Lanes::Jungle, 1)], 5).is_err());
+    }
+
+    #[test]
+    fn slots_must_fit_in_max_crew() {
+        assert!(validate_lanes(&[slot(Lanes::Mid, 3), slot(Lanes::Jungle, 2)], 5).is_ok());
+        assert!(validate_lanes(&[slot(Lanes::Mid, 3), slot(Lanes::Jungle, 3)], 5).is_err());
+        assert!(validate_lanes(&[slot(Lanes::Mid, 6)], 5).is_err());
+    }
+
+    #[test]
+    fn huge_slot_counts_do_not_overflow() {
+        let lanes = [slot(Lanes::Mid, i32::MAX), slot(Lanes::Jungle, i32::MAX)];
+
+        assert!(validate_lanes(&lanes, i32::MAX).is_err());
+    }
+}
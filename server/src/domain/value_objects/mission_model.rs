@@ -1,15 +1,24 @@
+use anyhow::Result;
 use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Text},
+};
 use serde::{Deserialize, Serialize};
 
 use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::value_objects::{
+    mission_lane_model::{LaneSlotModel, MissionLaneModel},
+    mission_statuses::MissionStatuses,
+    rank_requirement_model::RankRequirementModel,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MissionModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub crew_count: i64,
     pub created_at: NaiveDateTime,
@@ -18,6 +27,23 @@ pub struct MissionModel {
     pub difficulty: String,
     pub base_points: i32,
     pub due_date: Option<NaiveDateTime>,
+    pub archived_at: Option<NaiveDateTime>,
+    pub series_id: Option<i32>,
+    pub requires_approval: bool,
+    #[serde(flatten)]
+    pub rank_requirement: RankRequirementModel,
+    pub tags: Vec<String>,
+    pub lanes: Vec<MissionLaneModel>,
+    // Highlighted match, only set for full-text searches
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct MissionSnippetModel {
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Text)]
+    pub snippet: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -27,25 +53,58 @@ pub struct AddMissionModel {
     pub max_crew: i32,
     pub difficulty: String,
     pub due_date: Option<NaiveDateTime>,
+    pub tags: Option<Vec<String>>,
+    // Joining creates a request the chief accepts or rejects; defaults to open joining
+    pub requires_approval: Option<bool>,
+    pub lanes: Option<Vec<LaneSlotModel>>,
+    // min_rank, max_rank and min_total_points; missing bounds are not enforced
+    #[serde(flatten)]
+    pub rank_requirement: RankRequirementModel,
+}
+
+pub const DIFFICULTIES: [&str; 3] = ["EASY", "NORMAL", "HARD"];
+
+/// Accepts EASY, NORMAL or HARD in any case and with surrounding spaces, and returns the
+/// stored form that scoring and filtering expect.
+pub fn normalize_difficulty(difficulty: &str) -> Result<String> {
+    let normalized = difficulty.trim().to_uppercase();
+    if !DIFFICULTIES.contains(&normalized.as_str()) {
+        return Err(anyhow::anyhow!(
+            "Invalid difficulty: {}. Use one of {}",
+            difficulty,
+            DIFFICULTIES.join(", ")
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Points a mission of this normalized difficulty is worth; anything else counts as NORMAL.
+pub fn difficulty_base_points(difficulty: &str) -> i32 {
+    match difficulty {
+        "EASY" => 1,
+        "HARD" => 5,
+        _ => 3,
+    }
 }
 
 impl AddMissionModel {
     pub fn to_entity(&self, chief_id: i32) -> AddMissionEntity {
-        let points = match self.difficulty.to_uppercase().as_str() {
-            "EASY" => 1,
-            "HARD" => 5,
-            _ => 3, 
-        };
+        let points = difficulty_base_points(&self.difficulty);
 
         AddMissionEntity {
             chief_id,
             name: self.name.clone(),
-            status: MissionStatuses::Open.to_string(),
+            status: MissionStatuses::Open,
             description: self.description.clone(),
             max_crew: self.max_crew,
             difficulty: self.difficulty.clone(),
             base_points: points,
             due_date: self.due_date,
+            series_id: None,
+            requires_approval: self.requires_approval.unwrap_or(false),
+            min_rank: self.rank_requirement.min_rank,
+            max_rank: self.rank_requirement.max_rank,
+            min_total_points: self.rank_requirement.min_total_points,
         }
     }
 }
@@ -54,29 +113,87 @@ impl AddMissionModel {
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub status: Option<String>,
+    // Status only changes through mission operations; a different value here is rejected
+    pub status: Option<MissionStatuses>,
     pub max_crew: Option<i32>,
     pub difficulty: Option<String>,
     pub due_date: Option<NaiveDateTime>,
+    // Replaces the whole tag set when given
+    pub tags: Option<Vec<String>>,
+    pub requires_approval: Option<bool>,
+    // Replaces all declared lanes when given
+    pub lanes: Option<Vec<LaneSlotModel>>,
+    // Only the bounds given are changed
+    #[serde(flatten)]
+    pub rank_requirement: RankRequirementModel,
+    // Drops every bound before the ones given above are applied
+    pub clear_rank_requirement: Option<bool>,
 }
 
 impl EditMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> EditMissionEntity {
-        let new_points = self.difficulty.as_ref().map(|d| match d.to_uppercase().as_str() {
-            "EASY" => 1,
-            "HARD" => 5,
-            _ => 3,
-        });
+    /// Some(None) clears a bound that was left out when the requirement is being cleared.
+    fn rank_bound<B>(&self, bound: Option<B>) -> Option<Option<B>> {
+        if self.clear_rank_requirement.unwrap_or(false) {
+            Some(bound)
+        } else {
+            bound.map(Some)
+        }
+    }
+
+    pub fn to_entity(&self) -> EditMissionEntity {
+        let new_points = self.difficulty.as_deref().map(difficulty_base_points);
 
         EditMissionEntity {
-            chief_id,
             name: self.name.clone(),
-            status: self.status.clone(),
             description: self.description.clone(),
             max_crew: self.max_crew,
             difficulty: self.difficulty.clone(),
             base_points: new_points,
             due_date: self.due_date,
+            requires_approval: self.requires_approval,
+            min_rank: self.rank_bound(self.rank_requirement.min_rank),
+            max_rank: self.rank_bound(self.rank_requirement.max_rank),
+            min_total_points: self.rank_bound(self.rank_requirement.min_total_points),
+            due_reminded_at: self.due_date.map(|_| None),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_difficulty_trims_and_uppercases() {
+        assert_eq!(normalize_difficulty(" hard ").unwrap(), "HARD");
+        assert_eq!(normalize_difficulty("NoRmAl").unwrap(), "NORMAL");
+        assert_eq!(normalize_difficulty("\teasy\n").unwrap(), "EASY");
+    }
+
+    #[test]
+    fn normalize_difficulty_rejects_unknown_values() {
+        assert!(normalize_difficulty("extreme").is_err());
+        assert!(normalize_difficulty("").is_err());
+        assert!(normalize_difficulty("ha rd").is_err());
+    }
+
+    #[test]
+    fn normalized_difficulty_is_scored_as_given() {
+        let add_mission_model = AddMissionModel {
+            name: "Push mid".to_string(),
+            description: None,
+            max_crew: 3,
+            difficulty: normalize_difficulty(" hard ").unwrap(),
+            due_date: None,
+            tags: None,
+            requires_approval: None,
+            lanes: None,
+            rank_requirement: RankRequirementModel::default(),
+        };
+
+        let entity = add_mission_model.to_entity(1);
+
+        assert_eq!(entity.difficulty, "HARD");
+        assert_eq!(entity.base_points, 5);
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{NaiveDate, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::recurrence_frequencies::RecurrenceFrequencies;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionSeriesModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: i32,
+    pub difficulty: String,
+    // Each occurrence is due at this time (UTC) on its day
+    pub due_time: Option<NaiveTime>,
+    pub frequency: RecurrenceFrequencies,
+    #[serde(default)]
+    pub weekdays: Vec<Weekday>,
+    // Defaults to today
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionSeriesModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub difficulty: Option<String>,
+    pub due_time: Option<NaiveTime>,
+    pub frequency: Option<RecurrenceFrequencies>,
+    pub weekdays: Option<Vec<Weekday>>,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+    // Each one drops the stored value; a new value sent alongside still wins
+    pub clear_description: Option<bool>,
+    pub clear_due_time: Option<bool>,
+    pub clear_end_date: Option<bool>,
+    pub clear_max_occurrences: Option<bool>,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum MissionSorts {
+    Recent,
+    DueDate,
+    BasePoints,
+    // EASY, NORMAL, HARD
+    Difficulty,
+    CrewCount,
+}
+
+impl MissionSorts {
+    /// Soonest deadline first; everything else shows the largest or newest first.
+    pub fn default_direction(&self) -> SortDirections {
+        match self {
+            MissionSorts::DueDate => SortDirections::Asc,
+            MissionSorts::Recent
+            | MissionSorts::BasePoints
+            | MissionSorts::Difficulty
+            | MissionSorts::CrewCount => SortDirections::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SortDirections {
+    Asc,
+    Desc,
+}
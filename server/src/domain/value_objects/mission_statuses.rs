@@ -1,14 +1,44 @@
-use std::fmt::Display;
+use std::{fmt::Display, io::Write};
 
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+use crate::infrastructure::database::schema::sql_types::MissionStatus;
+
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = MissionStatus)]
 pub enum MissionStatuses {
     #[default]
     Open,
     InProgress,
     Completed,
     Failed,
+    Cancelled,
+}
+
+impl MissionStatuses {
+    pub const ALL: [MissionStatuses; 5] = [
+        MissionStatuses::Open,
+        MissionStatuses::InProgress,
+        MissionStatuses::Completed,
+        MissionStatuses::Failed,
+        MissionStatuses::Cancelled,
+    ];
+
+    pub fn try_from_str(status: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.to_string() == status)
+            .ok_or_else(|| anyhow::anyhow!("Invalid mission status: {}", status))
+    }
 }
 
 impl Display for MissionStatuses {
@@ -18,6 +48,21 @@ impl Display for MissionStatuses {
             MissionStatuses::InProgress => write!(f, "InProgress"),
             MissionStatuses::Completed => write!(f, "Completed"),
             MissionStatuses::Failed => write!(f, "Failed"),
+            MissionStatuses::Cancelled => write!(f, "Cancelled"),
         }
     }
 }
+
+impl ToSql<MissionStatus, Pg> for MissionStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<MissionStatus, Pg> for MissionStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(status)?)
+    }
+}
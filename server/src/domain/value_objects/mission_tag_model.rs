@@ -0,0 +1,120 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_TAGS_PER_MISSION: usize = 5;
+const TAG_MIN_LENGTH: usize = 2;
+const TAG_MAX_LENGTH: usize = 30;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum TagMatches {
+    // Missions carrying at least one of the tags
+    #[default]
+    Any,
+    // Missions carrying every one of the tags
+    All,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TagCountModel {
+    pub name: String,
+    pub mission_count: i64,
+}
+
+/// Lowercases the tag and joins its words with '-', so "Ranked Push" and "ranked_push"
+/// end up as the same tag. Anything other than letters, digits and combining marks
+/// separates words.
+pub fn normalize_tag(raw: &str) -> Result<String> {
+    let tag = raw
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && !is_combining_mark(c))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    let length = tag.chars().count();
+    if !(TAG_MIN_LENGTH..=TAG_MAX_LENGTH).contains(&length) {
+        return Err(anyhow::anyhow!(
+            "Tag \"{}\" must be between {} and {} characters long",
+            raw.trim(),
+            TAG_MIN_LENGTH,
+            TAG_MAX_LENGTH
+        ));
+    }
+
+    Ok(tag)
+}
+
+// Marks that aren't alphanumeric on their own but belong to the letter before them:
+// Latin diacritics and Thai vowel and tone marks
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}' | '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}'
+    )
+}
+
+/// Normalizes and de-duplicates the tags of one mission, keeping their order.
+pub fn normalize_tags(raw_tags: &[String]) -> Result<Vec<String>> {
+    let mut tags: Vec<String> = Vec::new();
+    for raw in raw_tags {
+        let tag = normalize_tag(raw)?;
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    if tags.len() > MAX_TAGS_PER_MISSION {
+        return Err(anyhow::anyhow!(
+            "A mission can have at most {} tags",
+            MAX_TAGS_PER_MISSION
+        ));
+    }
+
+    Ok(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spellings_of_the_same_tag_normalize_alike() {
+        for raw in ["Ranked Push", "ranked_push", "  RANKED--push!  "] {
+            assert_eq!(normalize_tag(raw).unwrap(), "ranked-push");
+        }
+    }
+
+    #[test]
+    fn keeps_thai_vowel_and_tone_marks() {
+        assert_eq!(normalize_tag("แรงค์ ภารกิจ").unwrap(), "แรงค์-ภารกิจ");
+        assert_eq!(normalize_tag("เล่น_ด้วยกัน").unwrap(), "เล่น-ด้วยกัน");
+    }
+
+    #[test]
+    fn rejects_tags_with_nothing_left() {
+        assert!(normalize_tag("").is_err());
+        assert!(normalize_tag("!!! ---").is_err());
+    }
+
+    #[test]
+    fn rejects_tags_outside_the_length_bounds() {
+        assert!(normalize_tag("a").is_err());
+        assert!(normalize_tag(&"a".repeat(TAG_MAX_LENGTH)).is_ok());
+        assert!(normalize_tag(&"a".repeat(TAG_MAX_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn normalize_tags_drops_duplicates_in_order() {
+        let raw = ["Duo".to_string(), "night owl".to_string(), "DUO".to_string()];
+
+        assert_eq!(normalize_tags(&raw).unwrap(), vec!["duo", "night-owl"]);
+    }
+
+    #[test]
+    fn normalize_tags_caps_the_count_after_dedup() {
+        let raw: Vec<String> = (0..=MAX_TAGS_PER_MISSION).map(|i| format!("tag{}", i)).collect();
+
+        assert!(normalize_tags(&raw).is_err());
+        assert!(normalize_tags(&raw[..MAX_TAGS_PER_MISSION]).is_ok());
+    }
+}
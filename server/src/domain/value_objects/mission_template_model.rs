@@ -0,0 +1,73 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_templates::{AddMissionTemplateEntity, EditMissionTemplateEntity},
+    value_objects::{
+        mission_lane_model::LaneSlotModel, rank_requirement_model::RankRequirementModel,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TemplateScopes {
+    Mine,
+    Global,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: String,
+    pub max_crew: i32,
+    pub due_in_hours: Option<i32>,
+}
+
+impl AddMissionTemplateModel {
+    pub fn to_entity(&self, owner_id: Option<i32>) -> AddMissionTemplateEntity {
+        AddMissionTemplateEntity {
+            owner_id,
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            difficulty: self.difficulty.clone(),
+            max_crew: self.max_crew,
+            due_in_hours: self.due_in_hours,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub max_crew: Option<i32>,
+    pub due_in_hours: Option<i32>,
+}
+
+impl EditMissionTemplateModel {
+    pub fn to_entity(&self) -> EditMissionTemplateEntity {
+        EditMissionTemplateEntity {
+            name: self.name.as_ref().map(|name| name.trim().to_string()),
+            description: self.description.clone(),
+            difficulty: self.difficulty.clone(),
+            max_crew: self.max_crew,
+            due_in_hours: self.due_in_hours,
+        }
+    }
+}
+
+/// Fields that replace the template's values for one new mission.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionFromTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub max_crew: Option<i32>,
+    pub due_date: Option<NaiveDateTime>,
+    pub tags: Option<Vec<String>>,
+    pub requires_approval: Option<bool>,
+    pub lanes: Option<Vec<LaneSlotModel>>,
+    #[serde(flatten)]
+    pub rank_requirement: RankRequirementModel,
+}
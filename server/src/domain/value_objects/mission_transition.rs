@@ -0,0 +1,194 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_statuses::MissionStatuses;
+
+/// What the guards need to know about a mission before it changes status.
+#[derive(Debug, Clone)]
+pub struct MissionTransitionContext {
+    pub chief_id: i32,
+    pub crew_count: i64,
+    pub crew_capacity: i64,
+}
+
+/// A checked status change, ready to be written together with its history row.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MissionTransitionModel {
+    pub from: MissionStatuses,
+    pub to: MissionStatuses,
+    // None when the server moves the mission on its own
+    pub actor_id: Option<i32>,
+}
+
+impl MissionStatuses {
+    /// Every edge the mission lifecycle allows out of this status.
+    pub fn next_statuses(&self) -> &'static [MissionStatuses] {
+        match self {
+            MissionStatuses::Open => &[
+                MissionStatuses::InProgress,
+                MissionStatuses::Failed,
+                MissionStatuses::Cancelled,
+            ],
+            MissionStatuses::InProgress => &[
+                MissionStatuses::Completed,
+                MissionStatuses::Failed,
+                MissionStatuses::Cancelled,
+            ],
+            MissionStatuses::Completed | MissionStatuses::Cancelled => &[],
+            // A failed mission can be retried with the same crew
+            MissionStatuses::Failed => &[MissionStatuses::InProgress, MissionStatuses::Cancelled],
+        }
+    }
+
+    pub fn can_transition_to(&self, to: MissionStatuses) -> bool {
+        self.next_statuses().contains(&to)
+    }
+
+    /// Checks the edge and its guards, returning the transition to record.
+    pub fn transition_to(
+        &self,
+        to: MissionStatuses,
+        actor_id: Option<i32>,
+        context: &MissionTransitionContext,
+    ) -> Result<MissionTransitionModel> {
+        if !self.can_transition_to(to) {
+            return Err(anyhow::anyhow!(
+                "Mission cannot move from {} to {}",
+                self,
+                to
+            ));
+        }
+
+        // Only the due-date sweep fails a mission that never started
+        if *self == MissionStatuses::Open && to == MissionStatuses::Failed && actor_id.is_some() {
+            return Err(anyhow::anyhow!(
+                "Mission cannot move from {} to {}",
+                self,
+                to
+            ));
+        }
+
+        if let Some(actor_id) = actor_id
+            && actor_id != context.chief_id
+        {
+            return Err(anyhow::anyhow!(
+                "Only the Chief can move the mission to {}",
+                to
+            ));
+        }
+
+        if to == MissionStatuses::InProgress {
+            if context.crew_count <= 0 {
+                return Err(anyhow::anyhow!(
+                    "Mission must have at least one crew member"
+                ));
+            }
+            if context.crew_count > context.crew_capacity {
+                return Err(anyhow::anyhow!(
+                    "Mission crew limit exceeded (Max: {})",
+                    context.crew_capacity
+                ));
+            }
+        }
+
+        Ok(MissionTransitionModel {
+            from: *self,
+            to,
+            actor_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHIEF_ID: i32 = 1;
+
+    fn context(crew_count: i64) -> MissionTransitionContext {
+        MissionTransitionContext {
+            chief_id: CHIEF_ID,
+            crew_count,
+            crew_capacity: 3,
+        }
+    }
+
+    #[test]
+    fn chief_starts_an_open_mission_with_crew() {
+        let transition = MissionStatuses::Open
+            .transition_to(MissionStatuses::InProgress, Some(CHIEF_ID), &context(2))
+            .unwrap();
+
+        assert_eq!(
+            transition,
+            MissionTransitionModel {
+                from: MissionStatuses::Open,
+                to: MissionStatuses::InProgress,
+                actor_id: Some(CHIEF_ID),
+            }
+        );
+    }
+
+    #[test]
+    fn finished_missions_cannot_move() {
+        for from in [MissionStatuses::Completed, MissionStatuses::Cancelled] {
+            for to in [MissionStatuses::Open, MissionStatuses::InProgress, MissionStatuses::Failed] {
+                assert!(from.transition_to(to, Some(CHIEF_ID), &context(1)).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn open_mission_cannot_skip_to_completed() {
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::Completed, Some(CHIEF_ID), &context(1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn only_the_server_fails_an_open_mission() {
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::Failed, Some(CHIEF_ID), &context(0))
+                .is_err()
+        );
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::Failed, None, &context(0))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn only_the_chief_moves_the_mission() {
+        let result =
+            MissionStatuses::InProgress.transition_to(MissionStatuses::Completed, Some(2), &context(1));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failed_mission_can_be_retried() {
+        assert!(
+            MissionStatuses::Failed
+                .transition_to(MissionStatuses::InProgress, Some(CHIEF_ID), &context(1))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn starting_needs_crew_within_capacity() {
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::InProgress, Some(CHIEF_ID), &context(0))
+                .is_err()
+        );
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::InProgress, Some(CHIEF_ID), &context(4))
+                .is_err()
+        );
+    }
+}
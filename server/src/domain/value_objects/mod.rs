@@ -1,6 +1,35 @@
+pub mod achievement_criteria;
+pub mod achievement_model;
 pub mod brawler_model;
+pub mod crew_model;
+pub mod join_request_statuses;
+pub mod lanes;
+pub mod leaderboard_model;
+pub mod leaderboard_window;
 pub mod mission_filter;
+pub mod mission_invitation_model;
+pub mod mission_lane_model;
 pub mod mission_model;
+pub mod mission_series_model;
+pub mod mission_sorts;
 pub mod mission_statuses;
+pub mod mission_tag_model;
+pub mod mission_template_model;
+pub mod mission_transition;
+pub mod notification_kinds;
+pub mod page_model;
+pub mod point_reasons;
+pub mod points_recompute_model;
+pub mod rank_requirement_model;
+pub mod rank_tiers;
+pub mod rating_model;
+pub mod rating_tags;
+pub mod recurrence_frequencies;
+pub mod recurrence_rule;
+pub mod reward_kinds;
+pub mod reward_model;
+pub mod season_model;
+pub mod streak_model;
+pub mod tier_progress_model;
 pub mod base64_image;
 pub mod uploaded_image;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum NotificationKinds {
+    MissionCancelled,
+    DueDateReminder,
+    MissionExpired,
+    JoinRequested,
+    JoinRequestAccepted,
+    JoinRequestRejected,
+    MissionInvitation,
+    InvitationAccepted,
+    InvitationDeclined,
+    WaitlistPromoted,
+    CrewRemoved,
+    ChiefTransferred,
+}
+
+impl Display for NotificationKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationKinds::MissionCancelled => write!(f, "MissionCancelled"),
+            NotificationKinds::DueDateReminder => write!(f, "DueDateReminder"),
+            NotificationKinds::MissionExpired => write!(f, "MissionExpired"),
+            NotificationKinds::JoinRequested => write!(f, "JoinRequested"),
+            NotificationKinds::JoinRequestAccepted => write!(f, "JoinRequestAccepted"),
+            NotificationKinds::JoinRequestRejected => write!(f, "JoinRequestRejected"),
+            NotificationKinds::MissionInvitation => write!(f, "MissionInvitation"),
+            NotificationKinds::InvitationAccepted => write!(f, "InvitationAccepted"),
+            NotificationKinds::InvitationDeclined => write!(f, "InvitationDeclined"),
+            NotificationKinds::WaitlistPromoted => write!(f, "WaitlistPromoted"),
+            NotificationKinds::CrewRemoved => write!(f, "CrewRemoved"),
+            NotificationKinds::ChiefTransferred => write!(f, "ChiefTransferred"),
+        }
+    }
+}
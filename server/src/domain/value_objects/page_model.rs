@@ -0,0 +1,178 @@
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+const OFFSET_TOKEN_PREFIX: &str = "offset:";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: i64,
+    pub limit: i64,
+    // Send back as ?offset_token= to fetch what follows; None on the last page. It encodes
+    // a plain row offset, so rows added in the meantime can shift what the next page holds
+    pub next_offset_token: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: i64, request: PageRequest) -> Self {
+        let next_offset = request.offset + items.len() as i64;
+        let next_offset_token = if !items.is_empty() && next_offset < total {
+            Some(URL_SAFE_NO_PAD.encode(format!("{}{}", OFFSET_TOKEN_PREFIX, next_offset)))
+        } else {
+            None
+        };
+
+        Self {
+            items,
+            total,
+            page: request.page,
+            limit: request.limit,
+            next_offset_token,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRequest {
+    pub page: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl PageRequest {
+    /// Resolves the query parameters of a list endpoint. An offset token takes precedence
+    /// over `page`; without either the first page is returned.
+    pub fn resolve(
+        page: Option<i64>,
+        limit: Option<i64>,
+        offset_token: Option<&str>,
+        default_limit: i64,
+        max_limit: i64,
+    ) -> Result<Self> {
+        let limit = limit.unwrap_or(default_limit).clamp(1, max_limit);
+
+        if let Some(offset_token) = offset_token.filter(|offset_token| !offset_token.is_empty()) {
+            let offset = URL_SAFE_NO_PAD
+                .decode(offset_token)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .and_then(|token| token.strip_prefix(OFFSET_TOKEN_PREFIX)?.parse::<i64>().ok())
+                .filter(|offset| *offset >= 0)
+                .ok_or_else(|| anyhow::anyhow!("Invalid offset_token"))?;
+
+            return Ok(Self {
+                page: offset / limit + 1,
+                limit,
+                offset,
+            });
+        }
+
+        let page = page.unwrap_or(1);
+        if page < 1 {
+            return Err(anyhow::anyhow!("page must be at least 1"));
+        }
+
+        let offset = (page - 1)
+            .checked_mul(limit)
+            .ok_or_else(|| anyhow::anyhow!("page is too large"))?;
+
+        Ok(Self {
+            page,
+            limit,
+            offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(
+        page: Option<i64>,
+        limit: Option<i64>,
+        offset_token: Option<&str>,
+    ) -> Result<PageRequest> {
+        PageRequest::resolve(page, limit, offset_token, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE)
+    }
+
+    fn token(raw: &str) -> String {
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    #[test]
+    fn defaults_to_the_first_page() {
+        assert_eq!(
+            resolve(None, None, None).unwrap(),
+            PageRequest {
+                page: 1,
+                limit: DEFAULT_PAGE_SIZE,
+                offset: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn clamps_the_limit() {
+        assert_eq!(resolve(None, Some(0), None).unwrap().limit, 1);
+        assert_eq!(resolve(None, Some(10_000), None).unwrap().limit, MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn page_maps_to_an_offset() {
+        let request = resolve(Some(3), Some(10), None).unwrap();
+
+        assert_eq!((request.page, request.offset), (3, 20));
+    }
+
+    #[test]
+    fn rejects_pages_below_one() {
+        assert!(resolve(Some(0), None, None).is_err());
+        assert!(resolve(Some(-1), None, None).is_err());
+    }
+
+    #[test]
+    fn rejects_a_page_whose_offset_overflows() {
+        assert!(resolve(Some(i64::MAX), Some(MAX_PAGE_SIZE), None).is_err());
+    }
+
+    #[test]
+    fn offset_token_wins_over_page() {
+        let request = resolve(Some(1), Some(10), Some(&token("offset:25"))).unwrap();
+
+        assert_eq!((request.page, request.offset), (3, 25));
+    }
+
+    #[test]
+    fn empty_offset_token_is_ignored() {
+        assert_eq!(resolve(Some(2), Some(10), Some("")).unwrap().offset, 10);
+    }
+
+    #[test]
+    fn rejects_bad_offset_tokens() {
+        for bad in [
+            "not base64!".to_string(),
+            token("25"),
+            token("offset:"),
+            token("offset:abc"),
+            token("offset:-1"),
+        ] {
+            assert!(resolve(None, None, Some(&bad)).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn next_token_follows_the_items_until_the_last_page() {
+        let request = resolve(Some(1), Some(2), None).unwrap();
+        let first = Page::new(vec![1, 2], 3, request);
+        let next = resolve(None, Some(2), first.next_offset_token.as_deref()).unwrap();
+
+        assert_eq!(next.offset, 2);
+        assert_eq!(Page::new(vec![3], 3, next).next_offset_token, None);
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PointReasons {
+    MissionCompleted,
+    EarlyCompletionBonus,
+    LateCompletionPenalty,
+    MissionFailedPenalty,
+    MvpBonus,
+    StreakBonus,
+    Recompute,
+    Adjustment,
+}
+
+impl Display for PointReasons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointReasons::MissionCompleted => write!(f, "MissionCompleted"),
+            PointReasons::EarlyCompletionBonus => write!(f, "EarlyCompletionBonus"),
+            PointReasons::LateCompletionPenalty => write!(f, "LateCompletionPenalty"),
+            PointReasons::MissionFailedPenalty => write!(f, "MissionFailedPenalty"),
+            PointReasons::MvpBonus => write!(f, "MvpBonus"),
+            PointReasons::StreakBonus => write!(f, "StreakBonus"),
+            PointReasons::Recompute => write!(f, "Recompute"),
+            PointReasons::Adjustment => write!(f, "Adjustment"),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Bool, Integer, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::value_objects::mission_statuses::MissionStatuses,
+    infrastructure::database::schema::sql_types::MissionStatus,
+};
+
+/// One brawler's part in a finished mission, as chief or crew.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct MissionParticipationModel {
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Bool)]
+    pub is_chief: bool,
+    #[diesel(sql_type = MissionStatus)]
+    pub status: MissionStatuses,
+    #[diesel(sql_type = Integer)]
+    pub base_points: i32,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub due_date: Option<NaiveDateTime>,
+    #[diesel(sql_type = Timestamp)]
+    pub finished_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerPointsModel {
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub username: String,
+    #[diesel(sql_type = Integer)]
+    pub total_points: i32,
+}
+
+/// A ledger entry that doesn't come from a mission, e.g. a streak bonus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerPointsModel {
+    pub brawler_id: i32,
+    pub points: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointsDiffModel {
+    pub brawler_id: i32,
+    pub username: String,
+    pub current_points: i32,
+    pub expected_points: i32,
+    pub diff: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointsRecomputeReportModel {
+    pub dry_run: bool,
+    pub brawlers_checked: usize,
+    pub diffs: Vec<PointsDiffModel>,
+}
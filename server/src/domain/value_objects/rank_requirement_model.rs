@@ -0,0 +1,172 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{rank_tiers::RankTiers, tier_progress_model::TierProgressModel};
+
+/// Who may join a mission: a rank tier range and/or a total_points floor.
+/// Every bound that is set has to be met.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct RankRequirementModel {
+    pub min_rank: Option<RankTiers>,
+    pub max_rank: Option<RankTiers>,
+    pub min_total_points: Option<i32>,
+}
+
+impl RankRequirementModel {
+    pub fn validate(&self) -> Result<()> {
+        if let (Some(min_rank), Some(max_rank)) = (self.min_rank, self.max_rank)
+            && min_rank > max_rank
+        {
+            return Err(anyhow::anyhow!(
+                "min_rank ({}) cannot be higher than max_rank ({})",
+                min_rank,
+                max_rank
+            ));
+        }
+        if let Some(min_total_points) = self.min_total_points
+            && min_total_points < 0
+        {
+            return Err(anyhow::anyhow!("min_total_points cannot be negative"));
+        }
+        Ok(())
+    }
+
+    pub fn allows(&self, tier: RankTiers, total_points: i32) -> bool {
+        self.min_rank.is_none_or(|min_rank| tier >= min_rank)
+            && self.max_rank.is_none_or(|max_rank| tier <= max_rank)
+            && self.min_total_points.is_none_or(|min_total_points| total_points >= min_total_points)
+    }
+
+    /// Fails with the requirement spelled out when the brawler doesn't meet it.
+    /// `thresholds[i]` is the minimum total_points for `RankTiers::ALL[i]`.
+    pub fn ensure_met(&self, total_points: i32, thresholds: &[i32]) -> Result<()> {
+        let tier = TierProgressModel::from_points(total_points, thresholds).tier;
+        if self.allows(tier, total_points) {
+            return Ok(());
+        }
+
+        let mut requirements: Vec<String> = Vec::new();
+        match (self.min_rank, self.max_rank) {
+            (Some(min_rank), Some(max_rank)) if min_rank == max_rank => {
+                requirements.push(format!("{} rank", min_rank))
+            }
+            (Some(min_rank), Some(max_rank)) => {
+                requirements.push(format!("{} to {} rank", min_rank, max_rank))
+            }
+            (Some(min_rank), None) => requirements.push(format!("{} rank or higher", min_rank)),
+            (None, Some(max_rank)) => requirements.push(format!("{} rank or lower", max_rank)),
+            (None, None) => {}
+        }
+        if let Some(min_total_points) = self.min_total_points {
+            requirements.push(format!("at least {} total points", min_total_points));
+        }
+
+        Err(anyhow::anyhow!(
+            "This mission requires {}; you are {} with {} total points",
+            requirements.join(" and "),
+            tier,
+            total_points
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: [i32; 6] = [0, 100, 300, 600, 1000, 1500];
+
+    #[test]
+    fn validate_rejects_min_rank_above_max_rank() {
+        let requirement = RankRequirementModel {
+            min_rank: Some(RankTiers::Gold),
+            max_rank: Some(RankTiers::Silver),
+            min_total_points: None,
+        };
+
+        assert!(requirement.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_negative_min_total_points() {
+        let requirement = RankRequirementModel {
+            min_total_points: Some(-1),
+            ..Default::default()
+        };
+
+        assert!(requirement.validate().is_err());
+    }
+
+    #[test]
+    fn validate_accepts_a_single_tier_range() {
+        let requirement = RankRequirementModel {
+            min_rank: Some(RankTiers::Gold),
+            max_rank: Some(RankTiers::Gold),
+            min_total_points: Some(0),
+        };
+
+        assert!(requirement.validate().is_ok());
+    }
+
+    #[test]
+    fn ensure_met_passes_without_requirements() {
+        assert!(RankRequirementModel::default().ensure_met(0, &THRESHOLDS).is_ok());
+    }
+
+    #[test]
+    fn ensure_met_uses_the_tier_for_total_points() {
+        let requirement = RankRequirementModel {
+            min_rank: Some(RankTiers::Gold),
+            ..Default::default()
+        };
+
+        assert!(requirement.ensure_met(299, &THRESHOLDS).is_err());
+        assert!(requirement.ensure_met(300, &THRESHOLDS).is_ok());
+    }
+
+    #[test]
+    fn ensure_met_rejects_brawlers_above_max_rank() {
+        let requirement = RankRequirementModel {
+            max_rank: Some(RankTiers::Silver),
+            ..Default::default()
+        };
+
+        assert!(requirement.ensure_met(299, &THRESHOLDS).is_ok());
+        let err = requirement.ensure_met(300, &THRESHOLDS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "This mission requires Silver rank or lower; you are Gold with 300 total points"
+        );
+    }
+
+    #[test]
+    fn ensure_met_spells_out_every_unmet_bound() {
+        let requirement = RankRequirementModel {
+            min_rank: Some(RankTiers::Silver),
+            max_rank: Some(RankTiers::Gold),
+            min_total_points: Some(250),
+        };
+
+        let err = requirement.ensure_met(150, &THRESHOLDS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "This mission requires Silver to Gold rank and at least 250 total points; \
+             you are Silver with 150 total points"
+        );
+    }
+
+    #[test]
+    fn ensure_met_names_a_single_tier_once() {
+        let requirement = RankRequirementModel {
+            min_rank: Some(RankTiers::Diamond),
+            max_rank: Some(RankTiers::Diamond),
+            min_total_points: None,
+        };
+
+        let err = requirement.ensure_met(50, &THRESHOLDS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "This mission requires Diamond rank; you are Bronze with 50 total points"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::sql_types::RankTier;
+
+// Declared lowest to highest, in the same order as the rank_tier enum, so both compare alike
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, AsExpression,
+    FromSqlRow,
+)]
+#[diesel(sql_type = RankTier)]
+pub enum RankTiers {
+    #[default]
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    Conqueror,
+}
+
+impl RankTiers {
+    pub const ALL: [RankTiers; 6] = [
+        RankTiers::Bronze,
+        RankTiers::Silver,
+        RankTiers::Gold,
+        RankTiers::Platinum,
+        RankTiers::Diamond,
+        RankTiers::Conqueror,
+    ];
+
+    pub fn try_from_str(tier: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.to_string() == tier)
+            .ok_or_else(|| anyhow::anyhow!("Invalid rank tier: {}", tier))
+    }
+}
+
+impl Display for RankTiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankTiers::Bronze => write!(f, "Bronze"),
+            RankTiers::Silver => write!(f, "Silver"),
+            RankTiers::Gold => write!(f, "Gold"),
+            RankTiers::Platinum => write!(f, "Platinum"),
+            RankTiers::Diamond => write!(f, "Diamond"),
+            RankTiers::Conqueror => write!(f, "Conqueror"),
+        }
+    }
+}
+
+impl ToSql<RankTier, Pg> for RankTiers {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<RankTier, Pg> for RankTiers {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let tier = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(tier)?)
+    }
+}
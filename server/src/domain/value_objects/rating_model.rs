@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Double, Integer, Nullable, Text},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_ratings::{MissionMvpEntity, MissionRatingEntity},
+    value_objects::rating_tags::RatingTags,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateBrawlerModel {
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Option<Vec<RatingTags>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteMvpModel {
+    pub nominee_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct MvpTallyModel {
+    #[diesel(sql_type = Integer)]
+    pub nominee_id: i32,
+    #[diesel(sql_type = BigInt)]
+    pub votes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct RatingTagCountModel {
+    #[diesel(sql_type = Text)]
+    pub tag: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, QueryableByName)]
+pub struct RatingAggregateModel {
+    #[diesel(sql_type = Nullable<Double>)]
+    pub rating_average: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mvp_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingSummaryModel {
+    pub brawler_id: i32,
+    pub rating_average: Option<f64>,
+    pub rating_count: i64,
+    pub mvp_count: i64,
+    pub tags: Vec<RatingTagCountModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionFeedbackModel {
+    pub mission_id: i32,
+    pub closes_at: NaiveDateTime,
+    pub is_open: bool,
+    pub my_ratings: Vec<MissionRatingEntity>,
+    pub my_mvp_vote: Option<i32>,
+    pub mvp_votes: Vec<MvpTallyModel>,
+    pub mvp: Option<MissionMvpEntity>,
+}
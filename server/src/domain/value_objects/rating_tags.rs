@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RatingTags {
+    GoodShotcaller,
+    TeamPlayer,
+    Carry,
+    Supportive,
+    GoodComms,
+    Friendly,
+}
+
+impl Display for RatingTags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatingTags::GoodShotcaller => write!(f, "GoodShotcaller"),
+            RatingTags::TeamPlayer => write!(f, "TeamPlayer"),
+            RatingTags::Carry => write!(f, "Carry"),
+            RatingTags::Supportive => write!(f, "Supportive"),
+            RatingTags::GoodComms => write!(f, "GoodComms"),
+            RatingTags::Friendly => write!(f, "Friendly"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::sql_types::RecurrenceFrequency;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = RecurrenceFrequency)]
+pub enum RecurrenceFrequencies {
+    Daily,
+    Weekly,
+}
+
+impl RecurrenceFrequencies {
+    pub fn try_from_str(frequency: &str) -> Result<Self> {
+        match frequency {
+            "Daily" => Ok(Self::Daily),
+            "Weekly" => Ok(Self::Weekly),
+            _ => Err(anyhow::anyhow!("Invalid recurrence frequency: {}", frequency)),
+        }
+    }
+}
+
+impl Display for RecurrenceFrequencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceFrequencies::Daily => write!(f, "Daily"),
+            RecurrenceFrequencies::Weekly => write!(f, "Weekly"),
+        }
+    }
+}
+
+impl ToSql<RecurrenceFrequency, Pg> for RecurrenceFrequencies {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<RecurrenceFrequency, Pg> for RecurrenceFrequencies {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let frequency = std::str::from_utf8(bytes.as_bytes())?;
+        Ok(Self::try_from_str(frequency)?)
+    }
+}
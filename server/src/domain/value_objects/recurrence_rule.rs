@@ -0,0 +1,141 @@
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::recurrence_frequencies::RecurrenceFrequencies;
+
+/// When a mission series produces occurrences: every day, or on chosen weekdays,
+/// until an optional end date or occurrence count is reached.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequencies,
+    pub weekdays: Vec<Weekday>,
+    pub end_date: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+}
+
+impl RecurrenceRule {
+    pub fn validate(&self) -> Result<()> {
+        if self.frequency == RecurrenceFrequencies::Weekly && self.weekdays.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Weekly recurrence needs at least one weekday"
+            ));
+        }
+        if let Some(max_occurrences) = self.max_occurrences
+            && max_occurrences < 1
+        {
+            return Err(anyhow::anyhow!("max_occurrences must be at least 1"));
+        }
+        Ok(())
+    }
+
+    /// First occurrence date on or after `from`, or None once the rule is exhausted.
+    pub fn next_on_or_after(&self, from: NaiveDate, occurrences_created: i32) -> Option<NaiveDate> {
+        if let Some(max_occurrences) = self.max_occurrences
+            && occurrences_created >= max_occurrences
+        {
+            return None;
+        }
+
+        let next = match self.frequency {
+            RecurrenceFrequencies::Daily => Some(from),
+            RecurrenceFrequencies::Weekly => from
+                .iter_days()
+                .take(7)
+                .find(|date| self.weekdays.contains(&date.weekday())),
+        }?;
+
+        match self.end_date {
+            Some(end_date) if next > end_date => None,
+            _ => Some(next),
+        }
+    }
+
+    /// Weekdays as stored in the database, e.g. ["Tue", "Thu"].
+    pub fn weekday_names(&self) -> Vec<String> {
+        match self.frequency {
+            RecurrenceFrequencies::Daily => Vec::new(),
+            RecurrenceFrequencies::Weekly => {
+                let mut weekdays = self.weekdays.clone();
+                weekdays.sort_by_key(|weekday| weekday.num_days_from_monday());
+                weekdays.dedup();
+                weekdays.iter().map(|weekday| weekday.to_string()).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Days;
+
+    use super::*;
+
+    // A Monday
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+    }
+
+    fn weekly(weekdays: Vec<Weekday>) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: RecurrenceFrequencies::Weekly,
+            weekdays,
+            end_date: None,
+            max_occurrences: None,
+        }
+    }
+
+    #[test]
+    fn daily_rule_falls_on_the_given_date() {
+        let rule = RecurrenceRule {
+            frequency: RecurrenceFrequencies::Daily,
+            weekdays: Vec::new(),
+            end_date: None,
+            max_occurrences: None,
+        };
+
+        assert_eq!(rule.next_on_or_after(monday(), 0), Some(monday()));
+    }
+
+    #[test]
+    fn weekly_rule_finds_the_next_listed_weekday() {
+        let rule = weekly(vec![Weekday::Thu, Weekday::Tue]);
+
+        assert_eq!(rule.next_on_or_after(monday(), 0), Some(monday() + Days::new(1)));
+        assert_eq!(
+            rule.next_on_or_after(monday() + Days::new(2), 0),
+            Some(monday() + Days::new(3))
+        );
+    }
+
+    #[test]
+    fn weekly_rule_wraps_into_the_next_week() {
+        let rule = weekly(vec![Weekday::Mon]);
+
+        assert_eq!(
+            rule.next_on_or_after(monday() + Days::new(1), 0),
+            Some(monday() + Days::new(7))
+        );
+    }
+
+    #[test]
+    fn rule_stops_after_the_end_date() {
+        let rule = RecurrenceRule {
+            end_date: Some(monday() + Days::new(1)),
+            ..weekly(vec![Weekday::Wed])
+        };
+
+        assert_eq!(rule.next_on_or_after(monday(), 0), None);
+    }
+
+    #[test]
+    fn rule_stops_after_max_occurrences() {
+        let rule = RecurrenceRule {
+            max_occurrences: Some(2),
+            ..weekly(vec![Weekday::Mon])
+        };
+
+        assert_eq!(rule.next_on_or_after(monday(), 1), Some(monday()));
+        assert_eq!(rule.next_on_or_after(monday(), 2), None);
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RewardKinds {
+    Frame,
+    Title,
+    NameColor,
+}
+
+impl Display for RewardKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RewardKinds::Frame => write!(f, "Frame"),
+            RewardKinds::Title => write!(f, "Title"),
+            RewardKinds::NameColor => write!(f, "NameColor"),
+        }
+    }
+}
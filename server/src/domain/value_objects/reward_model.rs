@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::Queryable;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::rewards::RewardRedemptionEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable)]
+pub struct OwnedRewardModel {
+    pub reward_id: i32,
+    pub code: String,
+    pub name: String,
+    pub kind: String,
+    pub value: String,
+    pub redeemed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedemptionReceiptModel {
+    pub redemption: RewardRedemptionEntity,
+    pub spendable_points: i32,
+}
@@ -0,0 +1,32 @@
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::seasons::SeasonEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct SeasonStandingModel {
+    #[diesel(sql_type = BigInt)]
+    pub rank: i64,
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = Integer)]
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SeasonStandingFilter {
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonLeaderboardModel {
+    pub season: SeasonEntity,
+    pub standings: Vec<SeasonStandingModel>,
+}
@@ -0,0 +1,38 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StreakModel {
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub last_active_date: Option<NaiveDate>,
+}
+
+impl StreakModel {
+    /// Returns the streak after an activity on `today`, or `None` when today was already counted.
+    pub fn advance(&self, today: NaiveDate) -> Option<StreakModel> {
+        if self.last_active_date == Some(today) {
+            return None;
+        }
+
+        let current_streak = if self.last_active_date == today.checked_sub_days(Days::new(1)) {
+            self.current_streak + 1
+        } else {
+            1
+        };
+
+        Some(StreakModel {
+            current_streak,
+            longest_streak: self.longest_streak.max(current_streak),
+            last_active_date: Some(today),
+        })
+    }
+
+    /// The stored streak only counts while the brawler was active today or yesterday.
+    pub fn current_as_of(&self, today: NaiveDate) -> i32 {
+        match self.last_active_date {
+            Some(last) if last >= today - Days::new(1) => self.current_streak,
+            _ => 0,
+        }
+    }
+}
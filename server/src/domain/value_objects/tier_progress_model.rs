@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::rank_tiers::RankTiers;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TierProgressModel {
+    pub level: i32,
+    pub tier: RankTiers,
+    pub next_tier: Option<RankTiers>,
+    pub next_tier_points: Option<i32>,
+    pub points_to_next: Option<i32>,
+    // 0.0 - 100.0 within the current tier, 100.0 once Conqueror is reached
+    pub progress: f64,
+}
+
+impl TierProgressModel {
+    /// `thresholds[i]` is the minimum total_points for `RankTiers::ALL[i]`.
+    pub fn from_points(total_points: i32, thresholds: &[i32]) -> Self {
+        let index = thresholds
+            .iter()
+            .rposition(|floor| total_points >= *floor)
+            .unwrap_or(0);
+
+        let floor = thresholds.get(index).copied().unwrap_or(0);
+        let next_tier_points = thresholds.get(index + 1).copied();
+
+        let progress = match next_tier_points {
+            Some(next) if next > floor => {
+                ((total_points - floor) as f64 / (next - floor) as f64 * 100.0).clamp(0.0, 100.0)
+            }
+            _ => 100.0,
+        };
+
+        Self {
+            level: index as i32 + 1,
+            tier: RankTiers::ALL[index],
+            next_tier: RankTiers::ALL.get(index + 1).copied(),
+            next_tier_points,
+            points_to_next: next_tier_points.map(|next| next - total_points),
+            progress,
+        }
+    }
+}
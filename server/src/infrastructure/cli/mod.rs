@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::use_cases::{
+        mission_templates::MissionTemplateUseCase, points_recompute::PointsRecomputeUseCase,
+    },
+    config::config_model::DotEnvyConfig,
+    domain::value_objects::mission_template_model::AddMissionTemplateModel,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementPostgres, crew_operation::CrewOperationPostgres,
+            mission_management::MissionManagementPostgres,
+            mission_templates::MissionTemplatePostgres, mission_viewing::MissionViewingPostgres,
+            notifications::NotificationPostgres, points_recompute::PointsRecomputePostgres,
+        },
+    },
+};
+
+pub const RECOMPUTE_POINTS: &str = "recompute-points";
+pub const SEED_TEMPLATES: &str = "seed-templates";
+
+/// `server recompute-points [--dry-run]`
+pub async fn recompute_points(
+    db_pool: Arc<PgPoolSquad>,
+    config: Arc<DotEnvyConfig>,
+    args: &[String],
+) -> Result<()> {
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+
+    let use_case =
+        PointsRecomputeUseCase::new(Arc::new(PointsRecomputePostgres::new(db_pool)), config);
+    let report = use_case.recompute(dry_run).await?;
+
+    println!(
+        "{} brawlers checked, {} out of sync{}",
+        report.brawlers_checked,
+        report.diffs.len(),
+        if report.dry_run { " (dry run, nothing written)" } else { "" }
+    );
+    for diff in &report.diffs {
+        println!(
+            "  #{:<6} {:<24} {:>8} -> {:<8} ({:+})",
+            diff.brawler_id, diff.username, diff.current_points, diff.expected_points, diff.diff
+        );
+    }
+
+    Ok(())
+}
+
+/// `server seed-templates <file.json>` where the file holds an array of templates.
+/// Global templates are matched by name, so re-running the seed updates them in place.
+pub async fn seed_templates(
+    db_pool: Arc<PgPoolSquad>,
+    config: Arc<DotEnvyConfig>,
+    args: &[String],
+) -> Result<()> {
+    let path = args
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Usage: {} <file.json>", SEED_TEMPLATES))?;
+    let templates: Vec<AddMissionTemplateModel> =
+        serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+    let use_case = MissionTemplateUseCase::new(
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(db_pool)),
+        config,
+    );
+    let (inserted, updated) = use_case.seed_global(templates).await?;
+
+    println!("{} global templates added, {} updated", inserted, updated);
+
+    Ok(())
+}
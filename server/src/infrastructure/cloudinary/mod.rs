@@ -22,14 +22,20 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
     let mut params: HashMap<String, String> = HashMap::new();
     params.insert("timestamp".to_string(), timestamp.clone());
     
-    if let Some(f) = option.folder {
-        if !f.is_empty() { params.insert("folder".to_string(), f); }
+    if let Some(f) = option.folder
+        && !f.is_empty()
+    {
+        params.insert("folder".to_string(), f);
     }
-    if let Some(p) = option.public_id {
-        if !p.is_empty() { params.insert("public_id".to_string(), p); }
+    if let Some(p) = option.public_id
+        && !p.is_empty()
+    {
+        params.insert("public_id".to_string(), p);
     }
-    if let Some(t) = option.transformation {
-        if !t.is_empty() { params.insert("transformation".to_string(), t); }
+    if let Some(t) = option.transformation
+        && !t.is_empty()
+    {
+        params.insert("transformation".to_string(), t);
     }
 
     let mut sorted_keys: Vec<_> = params.keys().collect();
@@ -0,0 +1,24 @@
+CREATE TABLE achievements (
+    id SERIAL PRIMARY KEY,
+    code VARCHAR(50) NOT NULL UNIQUE,
+    "name" VARCHAR(100) NOT NULL,
+    "description" TEXT NOT NULL,
+    icon VARCHAR(255) NOT NULL,
+    criteria VARCHAR(50) NOT NULL,
+    threshold INTEGER NOT NULL DEFAULT 1,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE brawler_achievements (
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    achievement_id INTEGER NOT NULL REFERENCES achievements(id),
+    unlocked_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (brawler_id, achievement_id)
+);
+
+INSERT INTO achievements (code, "name", "description", icon, criteria, threshold) VALUES
+    ('FIRST_JOIN', 'Assemble!', 'Join your first mission as a crew member.', 'handshake', 'MissionsJoined', 1),
+    ('FIRST_MISSION', 'First Blood', 'Complete your first mission.', 'sword', 'MissionsCompleted', 1),
+    ('CHIEF_10', 'Shotcaller', 'Complete 10 missions as chief.', 'crown', 'ChiefMissionsCompleted', 10),
+    ('STREAK_5', 'On Fire', 'Complete missions on 5 consecutive days.', 'flame', 'DailyStreak', 5),
+    ('HARD_MISSION', 'Legendary', 'Complete a HARD mission.', 'skull', 'HardMissionsCompleted', 1);
@@ -0,0 +1,4 @@
+ALTER TABLE brawlers
+DROP COLUMN current_streak,
+DROP COLUMN longest_streak,
+DROP COLUMN last_active_date;
@@ -0,0 +1,5 @@
+ALTER TABLE brawlers
+ADD COLUMN current_streak INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN longest_streak INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN last_active_date DATE;
+
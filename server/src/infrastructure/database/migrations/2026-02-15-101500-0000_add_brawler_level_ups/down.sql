@@ -0,0 +1 @@
+DROP TABLE IF EXISTS brawler_level_ups;
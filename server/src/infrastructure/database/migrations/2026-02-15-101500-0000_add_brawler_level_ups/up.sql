@@ -0,0 +1,11 @@
+CREATE TABLE brawler_level_ups (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    from_tier VARCHAR(20) NOT NULL,
+    to_tier VARCHAR(20) NOT NULL,
+    total_points INTEGER NOT NULL,
+    seen_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_brawler_level_ups_brawler_id ON brawler_level_ups (brawler_id);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS season_standings;
+
+DROP TABLE IF EXISTS season_points;
+
+DROP TABLE IF EXISTS seasons;
@@ -0,0 +1,31 @@
+CREATE TABLE seasons (
+    id SERIAL PRIMARY KEY,
+    "name" VARCHAR(100) NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP NOT NULL,
+    archived_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT chk_season_dates CHECK (ends_at > starts_at)
+);
+
+CREATE TABLE season_points (
+    season_id INTEGER NOT NULL REFERENCES seasons(id),
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    points INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (season_id, brawler_id)
+);
+
+CREATE TABLE season_standings (
+    season_id INTEGER NOT NULL REFERENCES seasons(id),
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    "rank" INTEGER NOT NULL,
+    points INTEGER NOT NULL,
+    archived_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (season_id, brawler_id)
+);
+
+CREATE INDEX idx_season_standings_rank ON season_standings (season_id, "rank");
+
+INSERT INTO seasons ("name", starts_at, ends_at)
+VALUES ('Season 1', date_trunc('day', now()), date_trunc('day', now()) + INTERVAL '30 days');
@@ -0,0 +1,9 @@
+CREATE TABLE point_ledger (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    points INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_point_ledger_brawler_created ON point_ledger (brawler_id, created_at);
+CREATE INDEX idx_point_ledger_created ON point_ledger (created_at);
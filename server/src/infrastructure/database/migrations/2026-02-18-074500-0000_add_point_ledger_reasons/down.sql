@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_point_ledger_mission;
+
+ALTER TABLE point_ledger
+    DROP COLUMN mission_id,
+    DROP COLUMN reason;
@@ -0,0 +1,5 @@
+ALTER TABLE point_ledger
+    ADD COLUMN reason VARCHAR(50) NOT NULL DEFAULT 'Adjustment',
+    ADD COLUMN mission_id INTEGER REFERENCES missions(id);
+
+CREATE INDEX idx_point_ledger_mission ON point_ledger (mission_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS mission_mvps;
+DROP TABLE IF EXISTS mission_mvp_votes;
+DROP TABLE IF EXISTS mission_ratings;
@@ -0,0 +1,33 @@
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id),
+    rater_id INTEGER NOT NULL REFERENCES brawlers(id),
+    ratee_id INTEGER NOT NULL REFERENCES brawlers(id),
+    score INTEGER NOT NULL CHECK (score BETWEEN 1 AND 5),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (mission_id, rater_id, ratee_id),
+    CHECK (rater_id <> ratee_id)
+);
+
+CREATE INDEX idx_mission_ratings_ratee ON mission_ratings (ratee_id);
+
+CREATE TABLE mission_mvp_votes (
+    mission_id INTEGER NOT NULL REFERENCES missions(id),
+    voter_id INTEGER NOT NULL REFERENCES brawlers(id),
+    nominee_id INTEGER NOT NULL REFERENCES brawlers(id),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, voter_id),
+    CHECK (voter_id <> nominee_id)
+);
+
+CREATE TABLE mission_mvps (
+    mission_id INTEGER PRIMARY KEY REFERENCES missions(id),
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    votes INTEGER NOT NULL,
+    bonus_points INTEGER NOT NULL,
+    awarded_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mission_mvps_brawler ON mission_mvps (brawler_id);
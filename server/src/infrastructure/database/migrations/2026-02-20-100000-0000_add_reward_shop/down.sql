@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS reward_redemptions;
+DROP TABLE IF EXISTS rewards;
+
+ALTER TABLE brawlers DROP COLUMN spendable_points;
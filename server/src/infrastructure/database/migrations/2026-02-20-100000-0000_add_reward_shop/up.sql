@@ -0,0 +1,35 @@
+ALTER TABLE brawlers ADD COLUMN spendable_points INTEGER NOT NULL DEFAULT 0 CHECK (spendable_points >= 0);
+
+-- Everything earned so far is spendable once the shop opens
+UPDATE brawlers SET spendable_points = GREATEST(total_points, 0);
+
+CREATE TABLE rewards (
+    id SERIAL PRIMARY KEY,
+    code VARCHAR(50) NOT NULL UNIQUE,
+    name VARCHAR(100) NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    value VARCHAR(255) NOT NULL,
+    cost INTEGER NOT NULL CHECK (cost >= 0),
+    -- NULL means unlimited
+    stock INTEGER CHECK (stock >= 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE reward_redemptions (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    reward_id INTEGER NOT NULL REFERENCES rewards(id),
+    cost INTEGER NOT NULL,
+    redeemed_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (brawler_id, reward_id)
+);
+
+INSERT INTO rewards (code, name, kind, value, cost, stock) VALUES
+    ('FRAME_BRONZE', 'Bronze Frame', 'Frame', 'frame-bronze', 20, NULL),
+    ('FRAME_DRAGON', 'Abyssal Dragon Frame', 'Frame', 'frame-abyssal-dragon', 150, 50),
+    ('TITLE_ROOKIE', 'Rookie Brawler', 'Title', 'Rookie Brawler', 10, NULL),
+    ('TITLE_SHOTCALLER', 'Shotcaller', 'Title', 'Shotcaller', 60, NULL),
+    ('TITLE_LEGEND', 'Legend of the Arena', 'Title', 'Legend of the Arena', 300, 10),
+    ('COLOR_CRIMSON', 'Crimson Name', 'NameColor', '#DC143C', 40, NULL),
+    ('COLOR_GOLD', 'Golden Name', 'NameColor', '#FFD700', 120, 25);
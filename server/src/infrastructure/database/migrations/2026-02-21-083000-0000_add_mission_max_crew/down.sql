@@ -0,0 +1 @@
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS missions_max_crew_positive;
@@ -0,0 +1,4 @@
+-- max_crew was added to the schema without a migration on some databases
+ALTER TABLE missions ADD COLUMN IF NOT EXISTS max_crew INTEGER NOT NULL DEFAULT 5;
+
+ALTER TABLE missions ADD CONSTRAINT missions_max_crew_positive CHECK (max_crew >= 1);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS mission_status_history;
+
+ALTER TABLE missions ALTER COLUMN status TYPE VARCHAR(255) USING status::TEXT;
+
+DROP TYPE IF EXISTS mission_status;
@@ -0,0 +1,28 @@
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed');
+
+-- Anything that isn't a known status was set through the old free-form edit, treat it as Open
+ALTER TABLE missions
+    ALTER COLUMN status TYPE mission_status
+    USING (
+        CASE
+            WHEN status IN ('Open', 'InProgress', 'Completed', 'Failed') THEN status::mission_status
+            ELSE 'Open'::mission_status
+        END
+    );
+
+CREATE TABLE mission_status_history (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id),
+    from_status mission_status,
+    to_status mission_status NOT NULL,
+    -- NULL when the server made the change on its own
+    actor_id INTEGER REFERENCES brawlers(id),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mission_status_history_mission ON mission_status_history (mission_id, created_at);
+
+-- Seed the current status of existing missions as their first history entry. Who made
+-- that change was never recorded, so the actor is left unknown
+INSERT INTO mission_status_history (mission_id, from_status, to_status, actor_id, created_at)
+SELECT id, NULL, status, NULL, updated_at FROM missions;
@@ -0,0 +1,19 @@
+DROP TABLE IF EXISTS notifications;
+
+DROP INDEX IF EXISTS idx_missions_archived_at;
+ALTER TABLE missions DROP COLUMN IF EXISTS archived_at;
+
+-- Postgres can't drop an enum value, so rebuild the type without Cancelled
+ALTER TYPE mission_status RENAME TO mission_status_old;
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed');
+
+UPDATE missions SET status = 'Failed' WHERE status = 'Cancelled';
+DELETE FROM mission_status_history WHERE to_status = 'Cancelled' OR from_status = 'Cancelled';
+
+ALTER TABLE missions
+    ALTER COLUMN status TYPE mission_status USING status::text::mission_status;
+ALTER TABLE mission_status_history
+    ALTER COLUMN from_status TYPE mission_status USING from_status::text::mission_status,
+    ALTER COLUMN to_status TYPE mission_status USING to_status::text::mission_status;
+
+DROP TYPE mission_status_old;
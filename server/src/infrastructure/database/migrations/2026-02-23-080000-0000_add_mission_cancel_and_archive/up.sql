@@ -0,0 +1,18 @@
+ALTER TYPE mission_status ADD VALUE IF NOT EXISTS 'Cancelled';
+
+-- Archiving only hides a finished mission from default listings, its outcome status stays intact
+ALTER TABLE missions ADD COLUMN archived_at TIMESTAMP;
+
+CREATE INDEX idx_missions_archived_at ON missions (archived_at);
+
+CREATE TABLE notifications (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    mission_id INTEGER REFERENCES missions(id),
+    kind VARCHAR(50) NOT NULL,
+    message TEXT NOT NULL,
+    read_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_notifications_brawler ON notifications (brawler_id, created_at DESC);
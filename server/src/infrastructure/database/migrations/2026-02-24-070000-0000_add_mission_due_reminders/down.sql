@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_missions_due_date;
+ALTER TABLE missions DROP COLUMN IF EXISTS due_reminded_at;
@@ -0,0 +1,4 @@
+-- Set once the pre-deadline reminder has gone out, cleared when the due date is edited
+ALTER TABLE missions ADD COLUMN due_reminded_at TIMESTAMP;
+
+CREATE INDEX idx_missions_due_date ON missions (due_date) WHERE deleted_at IS NULL;
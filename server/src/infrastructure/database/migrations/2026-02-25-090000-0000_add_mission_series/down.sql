@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_missions_series;
+ALTER TABLE missions DROP COLUMN IF EXISTS series_id;
+
+DROP TABLE IF EXISTS mission_series;
+DROP TYPE IF EXISTS recurrence_frequency;
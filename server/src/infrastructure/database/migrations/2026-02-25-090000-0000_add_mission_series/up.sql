@@ -0,0 +1,34 @@
+CREATE TYPE recurrence_frequency AS ENUM ('Daily', 'Weekly');
+
+CREATE TABLE mission_series (
+    id SERIAL PRIMARY KEY,
+    chief_id INTEGER NOT NULL REFERENCES brawlers(id),
+    -- Template every occurrence is created from
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    max_crew INTEGER NOT NULL CHECK (max_crew >= 1),
+    difficulty VARCHAR(20) NOT NULL,
+    due_time TIME,
+    -- Recurrence rule
+    frequency recurrence_frequency NOT NULL,
+    -- Short English day names, as chrono writes them; only Weekly series list any
+    weekdays TEXT[] NOT NULL DEFAULT '{}'
+        CHECK (weekdays <@ ARRAY['Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat', 'Sun']),
+    start_date DATE NOT NULL,
+    end_date DATE,
+    max_occurrences INTEGER CHECK (max_occurrences >= 1),
+    occurrences_created INTEGER NOT NULL DEFAULT 0,
+    last_occurrence_on DATE,
+    -- NULL once the rule has no dates left
+    next_occurrence_on DATE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mission_series_due ON mission_series (next_occurrence_on) WHERE is_active;
+CREATE INDEX idx_mission_series_chief ON mission_series (chief_id);
+
+ALTER TABLE missions ADD COLUMN series_id INTEGER REFERENCES mission_series(id);
+
+CREATE INDEX idx_missions_series ON missions (series_id);
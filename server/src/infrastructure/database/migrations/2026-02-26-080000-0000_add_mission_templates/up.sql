@@ -0,0 +1,26 @@
+CREATE TABLE mission_templates (
+    id SERIAL PRIMARY KEY,
+    -- NULL for shared global templates
+    owner_id INTEGER REFERENCES brawlers(id),
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    difficulty VARCHAR(20) NOT NULL,
+    max_crew INTEGER NOT NULL CHECK (max_crew >= 1),
+    -- Missions created from the template are due this many hours later
+    due_in_hours INTEGER CHECK (due_in_hours >= 1),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mission_templates_owner ON mission_templates (owner_id);
+CREATE UNIQUE INDEX idx_mission_templates_global_name ON mission_templates (name) WHERE owner_id IS NULL;
+
+INSERT INTO mission_templates (owner_id, name, description, difficulty, max_crew, due_in_hours) VALUES
+    (NULL, 'Ranked 5-Stack', 'Full party ranked grind, comms on.', 'NORMAL', 4, 4),
+    (NULL, 'Scrim BO3', 'Best of three against another squad.', 'HARD', 4, 24),
+    (NULL, 'Duo Climb', 'Two-player ranked session.', 'NORMAL', 1, 3),
+    (NULL, 'Custom 5v5 Practice', 'Practice lane swaps and team fights in a custom room.', 'EASY', 4, 24);
+
+-- Difficulty used to be stored as typed; scoring and filtering expect EASY, NORMAL or HARD
+UPDATE missions SET difficulty = UPPER(TRIM(difficulty)) WHERE difficulty <> UPPER(TRIM(difficulty));
+UPDATE mission_series SET difficulty = UPPER(TRIM(difficulty)) WHERE difficulty <> UPPER(TRIM(difficulty));
@@ -0,0 +1,22 @@
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    -- Normalized by the server: lowercase, words joined with '-'
+    name VARCHAR(30) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE mission_tags (
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, tag_id)
+);
+
+CREATE INDEX idx_mission_tags_tag ON mission_tags (tag_id);
+
+INSERT INTO tags (name) VALUES
+    ('ranked'),
+    ('scrim'),
+    ('casual'),
+    ('tournament'),
+    ('coaching');
@@ -0,0 +1,2 @@
+DROP INDEX idx_missions_search_vector;
+ALTER TABLE missions DROP COLUMN search_vector;
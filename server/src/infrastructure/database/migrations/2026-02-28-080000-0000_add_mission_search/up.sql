@@ -0,0 +1,8 @@
+-- Name hits outrank description hits. 'simple' keeps every word as typed, since stemming and
+-- stop-words of a language config would mangle Thai and mixed-language mission names
+ALTER TABLE missions ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('simple', coalesce(description, '')), 'B')
+) STORED;
+
+CREATE INDEX idx_missions_search_vector ON missions USING GIN (search_vector);
@@ -0,0 +1,3 @@
+DROP TABLE join_requests;
+DROP TYPE IF EXISTS join_request_status;
+ALTER TABLE missions DROP COLUMN requires_approval;
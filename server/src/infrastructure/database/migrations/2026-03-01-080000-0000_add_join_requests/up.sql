@@ -0,0 +1,20 @@
+-- When set, joining creates a pending request the chief has to accept
+ALTER TABLE missions ADD COLUMN requires_approval BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TYPE join_request_status AS ENUM ('Pending', 'Accepted', 'Rejected', 'Withdrawn');
+
+CREATE TABLE join_requests (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    status join_request_status NOT NULL DEFAULT 'Pending',
+    decided_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- A brawler can only have one open request per mission at a time
+CREATE UNIQUE INDEX idx_join_requests_pending
+    ON join_requests (mission_id, brawler_id)
+    WHERE status = 'Pending';
+
+CREATE INDEX idx_join_requests_brawler ON join_requests (brawler_id, created_at DESC);
@@ -0,0 +1,2 @@
+DROP TABLE mission_invitations;
+DROP TYPE IF EXISTS invitation_status;
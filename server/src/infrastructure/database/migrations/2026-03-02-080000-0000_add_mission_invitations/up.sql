@@ -0,0 +1,19 @@
+CREATE TYPE invitation_status AS ENUM ('Pending', 'Accepted', 'Declined', 'Expired');
+
+CREATE TABLE mission_invitations (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    invited_by INTEGER NOT NULL REFERENCES brawlers(id),
+    status invitation_status NOT NULL DEFAULT 'Pending',
+    expires_at TIMESTAMP NOT NULL,
+    decided_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- One open invitation per brawler and mission; lapsed ones are marked Expired before re-inviting
+CREATE UNIQUE INDEX idx_mission_invitations_pending
+    ON mission_invitations (mission_id, brawler_id)
+    WHERE status = 'Pending';
+
+CREATE INDEX idx_mission_invitations_brawler ON mission_invitations (brawler_id, created_at DESC);
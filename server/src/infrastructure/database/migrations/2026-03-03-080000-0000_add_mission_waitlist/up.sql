@@ -0,0 +1,10 @@
+CREATE TABLE mission_waitlist (
+    -- Serial id gives a strict first-come, first-served order
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (mission_id, brawler_id)
+);
+
+CREATE INDEX idx_mission_waitlist_brawler ON mission_waitlist (brawler_id);
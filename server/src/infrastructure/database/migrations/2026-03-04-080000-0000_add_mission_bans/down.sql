@@ -0,0 +1,3 @@
+ALTER TABLE crew_memberships DROP COLUMN from_transfer;
+
+DROP TABLE mission_bans;
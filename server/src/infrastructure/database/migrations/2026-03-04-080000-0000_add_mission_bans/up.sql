@@ -0,0 +1,13 @@
+-- Brawlers a chief removed from a mission; they cannot join it again
+CREATE TABLE mission_bans (
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    banned_by INTEGER NOT NULL REFERENCES brawlers(id),
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, brawler_id)
+);
+
+-- Set on the membership a chief gets by handing the mission to a crew member. It counts toward
+-- their daily limit as the mission they created, not as a join, so joined_at can stay truthful
+ALTER TABLE crew_memberships ADD COLUMN from_transfer BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,3 @@
+DROP TABLE mission_lanes;
+ALTER TABLE crew_memberships DROP COLUMN lane;
+DROP TYPE IF EXISTS lane;
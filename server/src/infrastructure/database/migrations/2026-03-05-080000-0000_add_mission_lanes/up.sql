@@ -0,0 +1,13 @@
+-- Declared in the order lanes are listed in, so ORDER BY lane sorts them the same way
+CREATE TYPE lane AS ENUM ('Slayer', 'Mid', 'Jungle', 'AbyssalDragon', 'Roaming');
+
+-- Lane a crew member claimed; NULL means they fill in wherever needed
+ALTER TABLE crew_memberships ADD COLUMN lane lane;
+
+-- Lanes the chief wants covered and how many crew each one takes
+CREATE TABLE mission_lanes (
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    lane lane NOT NULL,
+    slots INTEGER NOT NULL CHECK (slots > 0),
+    PRIMARY KEY (mission_id, lane)
+);
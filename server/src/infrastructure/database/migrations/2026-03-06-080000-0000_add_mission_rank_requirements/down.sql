@@ -0,0 +1,6 @@
+ALTER TABLE missions
+    DROP COLUMN min_total_points,
+    DROP COLUMN max_rank,
+    DROP COLUMN min_rank;
+
+DROP TYPE IF EXISTS rank_tier;
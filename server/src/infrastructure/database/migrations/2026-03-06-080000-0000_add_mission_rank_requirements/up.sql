@@ -0,0 +1,7 @@
+-- Who may join: a rank tier range and/or a total_points floor
+CREATE TYPE rank_tier AS ENUM ('Bronze', 'Silver', 'Gold', 'Platinum', 'Diamond', 'Conqueror');
+
+ALTER TABLE missions
+    ADD COLUMN min_rank rank_tier,
+    ADD COLUMN max_rank rank_tier,
+    ADD COLUMN min_total_points INTEGER CHECK (min_total_points >= 0);
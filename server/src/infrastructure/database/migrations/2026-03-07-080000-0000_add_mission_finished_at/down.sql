@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_missions_finished_at;
+
+ALTER TABLE missions DROP COLUMN finished_at;
@@ -0,0 +1,16 @@
+-- When the mission last reached Completed, Failed or Cancelled; NULL while it is still live.
+-- updated_at can't stand in for it since edits and chief transfers stamp it as well
+ALTER TABLE missions ADD COLUMN finished_at TIMESTAMP;
+
+UPDATE missions m
+SET finished_at = COALESCE(
+    (
+        SELECT MAX(h.created_at)
+        FROM mission_status_history h
+        WHERE h.mission_id = m.id AND h.to_status = m.status
+    ),
+    m.updated_at
+)
+WHERE m.status IN ('Completed', 'Failed', 'Cancelled');
+
+CREATE INDEX idx_missions_finished_at ON missions (finished_at) WHERE finished_at IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Streaks keep counting from here on, so the backfilled values are left in place
+SELECT 1;
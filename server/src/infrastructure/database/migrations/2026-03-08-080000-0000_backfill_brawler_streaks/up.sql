@@ -0,0 +1,37 @@
+-- Backfill streaks from missions already completed as chief or crew. Runs after finished_at
+-- exists, since updated_at also moves on edits and chief transfers
+WITH activity AS (
+    SELECT m.chief_id AS brawler_id, m.finished_at::DATE AS day
+    FROM missions m
+    WHERE m.status = 'Completed' AND m.deleted_at IS NULL
+    UNION
+    SELECT cm.brawler_id, m.finished_at::DATE AS day
+    FROM crew_memberships cm
+    INNER JOIN missions m ON m.id = cm.mission_id
+    WHERE m.status = 'Completed' AND m.deleted_at IS NULL
+),
+islands AS (
+    SELECT brawler_id, day, day - (ROW_NUMBER() OVER (PARTITION BY brawler_id ORDER BY day))::INT AS grp
+    FROM activity
+),
+runs AS (
+    SELECT brawler_id, COUNT(*)::INT AS length, MAX(day) AS last_day
+    FROM islands
+    GROUP BY brawler_id, grp
+),
+summary AS (
+    SELECT
+        brawler_id,
+        MAX(length) AS longest,
+        MAX(last_day) AS last_active,
+        (ARRAY_AGG(length ORDER BY last_day DESC))[1] AS current
+    FROM runs
+    GROUP BY brawler_id
+)
+UPDATE brawlers b
+SET
+    current_streak = s.current,
+    longest_streak = s.longest,
+    last_active_date = s.last_active
+FROM summary s
+WHERE b.id = s.brawler_id;
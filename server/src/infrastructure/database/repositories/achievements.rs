@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into};
+
+use crate::{
+    domain::{
+        entities::achievements::{AchievementEntity, UnlockAchievementEntity},
+        repositories::achievements::AchievementRepository,
+        value_objects::achievement_model::{
+            AchievementCatalogModel, BrawlerStatsModel, UnlockedAchievementModel,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{achievements, brawler_achievements},
+    },
+};
+
+pub struct AchievementPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AchievementPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for AchievementPostgres {
+    async fn get_all(&self) -> Result<Vec<AchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = achievements::table
+            .select(AchievementEntity::as_select())
+            .order_by(achievements::id.asc())
+            .load::<AchievementEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_catalog(&self) -> Result<Vec<AchievementCatalogModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                a.id,
+                a.code,
+                a.name,
+                a.description,
+                a.icon,
+                COUNT(ba.brawler_id) AS unlocked_count,
+                COALESCE(
+                    ROUND(COUNT(ba.brawler_id) * 100.0 / NULLIF((SELECT COUNT(*) FROM brawlers), 0), 2),
+                    0
+                )::FLOAT8 AS unlock_rate
+            FROM
+                achievements a
+            LEFT JOIN
+                brawler_achievements ba ON ba.achievement_id = a.id
+            GROUP BY
+                a.id
+            ORDER BY
+                a.id
+        "#;
+
+        let result = diesel::sql_query(sql).load::<AchievementCatalogModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_unlocked(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievementModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawler_achievements::table
+            .inner_join(achievements::table)
+            .filter(brawler_achievements::brawler_id.eq(brawler_id))
+            .select((
+                achievements::code,
+                achievements::name,
+                achievements::description,
+                achievements::icon,
+                brawler_achievements::unlocked_at,
+            ))
+            .order_by(brawler_achievements::unlocked_at.asc())
+            .load::<UnlockedAchievementModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_unlocked_ids(&self, brawler_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawler_achievements::table
+            .filter(brawler_achievements::brawler_id.eq(brawler_id))
+            .select(brawler_achievements::achievement_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_brawler_stats(&self, brawler_id: i32) -> Result<BrawlerStatsModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // The streak is the one StreakUseCase keeps on the brawler, so both always agree
+        let sql = r#"
+            WITH completed AS (
+                SELECT
+                    m.id,
+                    m.chief_id,
+                    m.difficulty
+                FROM
+                    missions m
+                WHERE
+                    m.deleted_at IS NULL
+                    AND m.status = 'Completed'
+                    AND (
+                        m.chief_id = $1
+                        OR EXISTS (
+                            SELECT 1 FROM crew_memberships cm
+                            WHERE cm.mission_id = m.id AND cm.brawler_id = $1
+                        )
+                    )
+            )
+            SELECT
+                (SELECT COUNT(*) FROM crew_memberships WHERE brawler_id = $1) AS missions_joined,
+                (SELECT COUNT(*) FROM completed) AS missions_completed,
+                (SELECT COUNT(*) FROM completed WHERE chief_id = $1) AS chief_missions_completed,
+                (SELECT COUNT(*) FROM completed WHERE UPPER(difficulty) = 'HARD') AS hard_missions_completed,
+                (SELECT COALESCE(MAX(longest_streak), 0)::BIGINT FROM brawlers WHERE id = $1) AS longest_streak
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<BrawlerStatsModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn unlock(&self, brawler_id: i32, achievement_ids: Vec<i32>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let values = achievement_ids
+            .into_iter()
+            .map(|achievement_id| UnlockAchievementEntity {
+                brawler_id,
+                achievement_id,
+            })
+            .collect::<Vec<_>>();
+
+        insert_into(brawler_achievements::table)
+            .values(&values)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
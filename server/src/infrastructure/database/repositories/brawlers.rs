@@ -4,23 +4,63 @@ use diesel::{
     prelude::*,
     insert_into,
 };
+use chrono::NaiveDateTime;
 use std::sync::Arc;
 
 use crate::{
     domain::{
         entities::{
-            brawlers::{Brawler, BrawlerEntity, RegisterBrawlerEntity}, 
-            missions::MissionEntity
+            brawler_level_ups::{AddBrawlerLevelUpEntity, BrawlerLevelUpEntity},
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity}, 
+            missions::MissionEntity,
+            point_ledger::{AddPointLedgerEntity, PointLedgerEntity},
         },
         repositories::brawlers::BrawlerRepository, 
-        value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+        value_objects::{
+            base64_image::Base64Image, leaderboard_model::LeaderboardRowModel,
+            point_reasons::PointReasons, streak_model::StreakModel, uploaded_image::UploadedImage,
+        },
     },
     infrastructure::{
         cloudinary::UploadImageOptions, 
-        database::{postgresql_connection::PgPoolSquad, schema::brawlers}
+        database::{postgresql_connection::PgPoolSquad, schema::{brawler_level_ups, brawlers, point_ledger}}
     },
 };
 
+// $1 = window start, NULL ranks by lifetime total_points.
+// Recompute corrections fix lifetime totals and aren't points earned inside a window.
+const RANKED_BRAWLERS_CTE: &str = r#"
+    WITH scores AS (
+        SELECT
+            b.id,
+            b.username,
+            b.display_name,
+            COALESCE(b.avatar_url, '') AS avatar_url,
+            b.total_points,
+            CASE
+                WHEN $1::TIMESTAMP IS NULL THEN b.total_points::BIGINT
+                ELSE COALESCE(
+                    (SELECT SUM(pl.points) FROM point_ledger pl WHERE pl.brawler_id = b.id AND pl.created_at >= $1 AND pl.reason <> 'Recompute'),
+                    0
+                )::BIGINT
+            END AS points
+        FROM
+            brawlers b
+    ),
+    ranked AS (
+        SELECT
+            ROW_NUMBER() OVER (ORDER BY points DESC, total_points DESC, id ASC) AS rank,
+            id,
+            username,
+            display_name,
+            avatar_url,
+            total_points,
+            points
+        FROM
+            scores
+    )
+"#;
+
 pub struct BrawlerPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -44,7 +84,7 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity> {
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = brawlers::table
@@ -88,17 +128,59 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(uploaded_image)
     }
 
-    async fn get_leaderboard(&self) -> std::result::Result<Vec<Brawler>, String> {
-        use crate::infrastructure::database::schema::brawlers::dsl::*;
-        
-        let mut connection = Arc::clone(&self.db_pool).get().map_err(|e| e.to_string())?;
+    async fn get_leaderboard(
+        &self,
+        window_start: Option<NaiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LeaderboardRowModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            "{} SELECT * FROM ranked ORDER BY rank LIMIT $2 OFFSET $3",
+            RANKED_BRAWLERS_CTE
+        );
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Timestamp>, _>(window_start)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .bind::<diesel::sql_types::BigInt, _>(offset)
+            .load::<LeaderboardRowModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn count_leaderboard(&self) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total = brawlers::table.count().get_result::<i64>(&mut conn)?;
+
+        Ok(total)
+    }
+
+    async fn get_leaderboard_around(
+        &self,
+        brawler_id: i32,
+        window_start: Option<NaiveDateTime>,
+        around: i64,
+    ) -> Result<Vec<LeaderboardRowModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            "{}, me AS (SELECT rank FROM ranked WHERE id = $2)
+            SELECT ranked.* FROM ranked, me
+            WHERE ranked.rank BETWEEN me.rank - $3 AND me.rank + $3
+            ORDER BY ranked.rank",
+            RANKED_BRAWLERS_CTE
+        );
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Timestamp>, _>(window_start)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .bind::<diesel::sql_types::BigInt, _>(around)
+            .load::<LeaderboardRowModel>(&mut conn)?;
 
-        brawlers
-            .order(total_points.desc()) 
-            .limit(10)                  
-            .load::<BrawlerEntity>(&mut connection)
-            .map(|entities| entities.into_iter().map(|e| e.into()).collect())
-            .map_err(|e| e.to_string())
+        Ok(result)
     }
 
     async fn crew_counting(&self, _mission_id: i32) -> Result<i32> {
@@ -133,12 +215,143 @@ impl BrawlerRepository for BrawlerPostgres {
             
         Ok(entity)
     }
-    async fn add_points(&self, brawler_id: i32, points: i32) -> Result<()> {
+    async fn add_points(&self, entry: AddPointLedgerEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let brawler_id = entry.brawler_id;
+        let points = entry.points;
+
+        let total_points = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let total_points = diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::total_points.eq(brawlers::total_points + points))
+                .returning(brawlers::total_points)
+                .get_result::<i32>(conn)?;
+
+            insert_into(point_ledger::table)
+                .values(&entry)
+                .execute(conn)?;
+
+            // Same points also count towards the season that is currently running
+            let season_sql = r#"
+                INSERT INTO season_points (season_id, brawler_id, points)
+                SELECT id, $1, $2
+                FROM seasons
+                WHERE starts_at <= now() AND ends_at > now() AND archived_at IS NULL
+                ORDER BY starts_at DESC
+                LIMIT 1
+                ON CONFLICT (season_id, brawler_id)
+                DO UPDATE SET points = season_points.points + EXCLUDED.points, updated_at = now()
+            "#;
+
+            diesel::sql_query(season_sql)
+                .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                .bind::<diesel::sql_types::Int4, _>(points)
+                .execute(conn)?;
+
+            // Spendable balance follows the same awards but never drops below zero
+            diesel::sql_query(
+                "UPDATE brawlers SET spendable_points = GREATEST(spendable_points + $2, 0) WHERE id = $1",
+            )
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .bind::<diesel::sql_types::Int4, _>(points)
+            .execute(conn)?;
+
+            Ok(total_points)
+        })?;
+
+        Ok(total_points)
+    }
+
+    async fn has_point_entry(
+        &self,
+        brawler_id: i32,
+        mission_id: i32,
+        reason: PointReasons,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::select(diesel::dsl::exists(
+            point_ledger::table
+                .filter(point_ledger::brawler_id.eq(brawler_id))
+                .filter(point_ledger::mission_id.eq(mission_id))
+                .filter(point_ledger::reason.eq(reason.to_string())),
+        ))
+        .get_result::<bool>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_point_history(
+        &self,
+        brawler_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PointLedgerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = point_ledger::table
+            .filter(point_ledger::brawler_id.eq(brawler_id))
+            .select(PointLedgerEntity::as_select())
+            .order_by((point_ledger::created_at.desc(), point_ledger::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .load::<PointLedgerEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn count_point_history(&self, brawler_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let total = point_ledger::table
+            .filter(point_ledger::brawler_id.eq(brawler_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+        Ok(total)
+    }
+
+    async fn update_streak(&self, brawler_id: i32, streak: StreakModel) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         diesel::update(brawlers::table)
-             .filter(brawlers::id.eq(brawler_id))
-             .set(brawlers::total_points.eq(brawlers::total_points + points))
-             .execute(&mut conn)?;
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::current_streak.eq(streak.current_streak),
+                brawlers::longest_streak.eq(streak.longest_streak),
+                brawlers::last_active_date.eq(streak.last_active_date),
+            ))
+            .execute(&mut conn)?;
         Ok(())
     }
+
+    async fn record_level_up(&self, level_up: AddBrawlerLevelUpEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(brawler_level_ups::table)
+            .values(&level_up)
+            .returning(brawler_level_ups::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_level_ups(&self, brawler_id: i32, unseen_only: bool) -> Result<Vec<BrawlerLevelUpEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = brawler_level_ups::table
+            .filter(brawler_level_ups::brawler_id.eq(brawler_id))
+            .into_boxed();
+
+        if unseen_only {
+            query = query.filter(brawler_level_ups::seen_at.is_null());
+        }
+
+        let result = query
+            .select(BrawlerLevelUpEntity::as_select())
+            .order_by(brawler_level_ups::created_at.desc())
+            .load::<BrawlerLevelUpEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn mark_level_ups_seen(&self, brawler_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::update(brawler_level_ups::table)
+            .filter(brawler_level_ups::brawler_id.eq(brawler_id))
+            .filter(brawler_level_ups::seen_at.is_null())
+            .set(brawler_level_ups::seen_at.eq(diesel::dsl::now))
+            .execute(&mut conn)?;
+        Ok(result)
+    }
 }
\ No newline at end of file
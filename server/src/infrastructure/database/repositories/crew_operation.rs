@@ -1,14 +1,33 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper, dsl::{delete, now}, insert_into, update,
+};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMemberShips,
+        entities::{
+            crew_memberships::CrewMemberShips,
+            join_requests::{AddJoinRequestEntity, JoinRequestEntity},
+            mission_bans::AddMissionBanEntity,
+            mission_waitlist::MissionWaitlistEntity,
+        },
         repositories::crew_operation::CrewOperationRepository,
+        value_objects::{
+            join_request_statuses::{JoinOutcomes, JoinRequestStatuses},
+            lanes::Lanes,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{
+            crew_memberships, join_requests, mission_bans, mission_lanes, mission_waitlist,
+            missions,
+        },
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
 
 pub struct CrewOperationPostgres {
@@ -21,14 +40,194 @@ impl CrewOperationPostgres {
     }
 }
 
+/// Locks the mission row and makes sure it still takes crew. Callers run this in a transaction.
+fn lock_joinable_mission(conn: &mut PgConnection, mission_id: i32) -> Result<()> {
+    // Concurrent joins on the same mission queue up behind this lock
+    let status = missions::table
+        .filter(missions::id.eq(mission_id))
+        .select(missions::status)
+        .for_update()
+        .first::<MissionStatuses>(conn)?;
+    // Re-checked under the lock so a cancel racing this join still wins
+    if status != MissionStatuses::Open && status != MissionStatuses::Failed {
+        return Err(anyhow::anyhow!("Mission is not joinable"));
+    }
+    Ok(())
+}
+
+/// Refuses brawlers the chief kicked; checked under the mission row lock so a pending
+/// invitation or join request can't slip a banned brawler back in.
+fn ensure_not_banned(conn: &mut PgConnection, crew_member_ships: &CrewMemberShips) -> Result<()> {
+    let banned = mission_bans::table
+        .filter(mission_bans::mission_id.eq(crew_member_ships.mission_id))
+        .filter(mission_bans::brawler_id.eq(crew_member_ships.brawler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+    if banned > 0 {
+        return Err(anyhow::anyhow!(
+            "Brawler was removed from this mission and cannot rejoin it"
+        ));
+    }
+    Ok(())
+}
+
+fn crew_size(conn: &mut PgConnection, mission_id: i32) -> Result<i64> {
+    let crew_count = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .count()
+        .get_result::<i64>(conn)?;
+    Ok(crew_count)
+}
+
+/// Makes sure the mission declares `lane` and it has a free slot, not counting the brawler's
+/// own claim. The mission row must already be locked.
+fn ensure_lane_open(
+    conn: &mut PgConnection,
+    crew_member_ships: &CrewMemberShips,
+    lane: Lanes,
+) -> Result<()> {
+    let slots = mission_lanes::table
+        .filter(mission_lanes::mission_id.eq(crew_member_ships.mission_id))
+        .filter(mission_lanes::lane.eq(lane))
+        .select(mission_lanes::slots)
+        .first::<i32>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("Mission has no {} lane", lane))?;
+
+    let filled = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+        .filter(crew_memberships::lane.eq(lane))
+        .filter(crew_memberships::brawler_id.ne(crew_member_ships.brawler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+    if filled >= slots as i64 {
+        return Err(anyhow::anyhow!(
+            "{} lane is already filled ({}/{})",
+            lane,
+            filled,
+            slots
+        ));
+    }
+    Ok(())
+}
+
+/// Inserts the membership; the mission row must already be locked.
+fn add_crew_member(
+    conn: &mut PgConnection,
+    crew_member_ships: CrewMemberShips,
+    lane: Option<Lanes>,
+) -> Result<()> {
+    insert_into(crew_memberships::table)
+        .values((
+            crew_memberships::brawler_id.eq(crew_member_ships.brawler_id),
+            crew_memberships::mission_id.eq(crew_member_ships.mission_id),
+            crew_memberships::lane.eq(lane),
+        ))
+        .execute(conn)?;
+    // Joining by any route takes the brawler off the mission's waitlist
+    delete(mission_waitlist::table)
+        .filter(mission_waitlist::mission_id.eq(crew_member_ships.mission_id))
+        .filter(mission_waitlist::brawler_id.eq(crew_member_ships.brawler_id))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Inserts the membership while holding the mission row lock; callers run this in a transaction.
+pub fn join_crew(
+    conn: &mut PgConnection,
+    crew_member_ships: CrewMemberShips,
+    crew_capacity: i32,
+) -> Result<()> {
+    lock_joinable_mission(conn, crew_member_ships.mission_id)?;
+    ensure_not_banned(conn, &crew_member_ships)?;
+
+    if crew_size(conn, crew_member_ships.mission_id)? >= crew_capacity as i64 {
+        return Err(anyhow::anyhow!("Mission is full"));
+    }
+
+    add_crew_member(conn, crew_member_ships, None)
+}
+
 #[async_trait]
 impl CrewOperationRepository for CrewOperationPostgres {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+    async fn join(&self, crew_member_ships: CrewMemberShips, crew_capacity: i32) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        insert_into(crew_memberships::table)
-            .values(crew_member_ships)
-            .execute(&mut conn)?;
-        Ok(())
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            join_crew(conn, crew_member_ships, crew_capacity)
+        })
+    }
+
+    async fn join_or_waitlist(
+        &self,
+        crew_member_ships: CrewMemberShips,
+        lane: Option<Lanes>,
+        crew_capacity: i32,
+    ) -> Result<JoinOutcomes> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<JoinOutcomes, anyhow::Error, _>(|conn| {
+            lock_joinable_mission(conn, crew_member_ships.mission_id)?;
+            ensure_not_banned(conn, &crew_member_ships)?;
+
+            let already_crew = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+                .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+                .count()
+                .get_result::<i64>(conn)?;
+            if already_crew > 0 {
+                return Err(anyhow::anyhow!("You are already in this mission's crew"));
+            }
+
+            if let Some(lane) = lane {
+                ensure_lane_open(conn, &crew_member_ships, lane)?;
+            }
+
+            if crew_size(conn, crew_member_ships.mission_id)? < crew_capacity as i64 {
+                add_crew_member(conn, crew_member_ships, lane)?;
+                return Ok(JoinOutcomes::Joined);
+            }
+
+            let waitlist_id = insert_into(mission_waitlist::table)
+                .values((
+                    mission_waitlist::mission_id.eq(crew_member_ships.mission_id),
+                    mission_waitlist::brawler_id.eq(crew_member_ships.brawler_id),
+                ))
+                .on_conflict_do_nothing()
+                .returning(mission_waitlist::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("You are already on this mission's waitlist"))?;
+
+            let position = mission_waitlist::table
+                .filter(mission_waitlist::mission_id.eq(crew_member_ships.mission_id))
+                .filter(mission_waitlist::id.le(waitlist_id))
+                .count()
+                .get_result::<i64>(conn)?;
+            Ok(JoinOutcomes::Waitlisted { position })
+        })
+    }
+
+    async fn claim_lane(&self, crew_member_ships: CrewMemberShips, lane: Option<Lanes>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            lock_joinable_mission(conn, crew_member_ships.mission_id)?;
+
+            if let Some(lane) = lane {
+                ensure_lane_open(conn, &crew_member_ships, lane)?;
+            }
+
+            let updated = update(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+                .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+                .set(crew_memberships::lane.eq(lane))
+                .execute(conn)?;
+            if updated == 0 {
+                return Err(anyhow::anyhow!("You are not in this mission's crew"));
+            }
+            Ok(())
+        })
     }
 
     async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
@@ -39,4 +238,213 @@ impl CrewOperationRepository for CrewOperationPostgres {
             .execute(&mut conn)?;
         Ok(())
     }
+
+    async fn get_waitlist(&self, mission_id: i32) -> Result<Vec<MissionWaitlistEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_waitlist::table
+            .filter(mission_waitlist::mission_id.eq(mission_id))
+            .select(MissionWaitlistEntity::as_select())
+            .order_by(mission_waitlist::id.asc())
+            .load::<MissionWaitlistEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn leave_waitlist(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let removed = delete(mission_waitlist::table)
+            .filter(mission_waitlist::mission_id.eq(crew_member_ships.mission_id))
+            .filter(mission_waitlist::brawler_id.eq(crew_member_ships.brawler_id))
+            .execute(&mut conn)?;
+        if removed == 0 {
+            return Err(anyhow::anyhow!("You are not on this mission's waitlist"));
+        }
+        Ok(())
+    }
+
+    async fn kick(&self, add_mission_ban_entity: AddMissionBanEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            // Same lock as joins, so the ban is in place before a racing join checks for it
+            missions::table
+                .filter(missions::id.eq(add_mission_ban_entity.mission_id))
+                .select(missions::id)
+                .for_update()
+                .first::<i32>(conn)?;
+
+            let removed = delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(add_mission_ban_entity.mission_id))
+                .filter(crew_memberships::brawler_id.eq(add_mission_ban_entity.brawler_id))
+                .execute(conn)?;
+            if removed == 0 {
+                return Err(anyhow::anyhow!("Brawler is not in this mission's crew"));
+            }
+
+            insert_into(mission_bans::table)
+                .values(add_mission_ban_entity)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+            Ok(())
+        })
+    }
+
+    async fn is_banned(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let count = mission_bans::table
+            .filter(mission_bans::mission_id.eq(mission_id))
+            .filter(mission_bans::brawler_id.eq(brawler_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+        Ok(count > 0)
+    }
+
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        new_chief_id: i32,
+    ) -> Result<Option<Lanes>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<Option<Lanes>, anyhow::Error, _>(|conn| {
+            // Locked so a second transfer or a join can't interleave with the swap
+            missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .select(missions::id)
+                .for_update()
+                .first::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Only the chief can transfer the mission"))?;
+
+            // Chiefs don't hold a lane, so the new chief's slot opens up for the crew
+            let freed_lane = delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(new_chief_id))
+                .returning(crew_memberships::lane)
+                .get_result::<Option<Lanes>>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("The new chief must be a member of the crew"))?;
+
+            // The old chief joins now without a lane; from_transfer keeps this from counting
+            // as a join against their daily limit
+            insert_into(crew_memberships::table)
+                .values((
+                    crew_memberships::mission_id.eq(mission_id),
+                    crew_memberships::brawler_id.eq(chief_id),
+                    crew_memberships::from_transfer.eq(true),
+                ))
+                .execute(conn)?;
+
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    missions::chief_id.eq(new_chief_id),
+                    missions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+            Ok(freed_lane)
+        })
+    }
+
+    async fn add_join_request(&self, add_join_request_entity: AddJoinRequestEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(join_requests::table)
+            .values(add_join_request_entity)
+            .returning(join_requests::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_join_request(&self, request_id: i32) -> Result<JoinRequestEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = join_requests::table
+            .filter(join_requests::id.eq(request_id))
+            .select(JoinRequestEntity::as_select())
+            .first::<JoinRequestEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Join request not found"))?;
+        Ok(result)
+    }
+
+    async fn get_join_requests_by_mission(
+        &self,
+        mission_id: i32,
+        status: JoinRequestStatuses,
+    ) -> Result<Vec<JoinRequestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        // Oldest first so the chief works through the queue in arrival order
+        let result = join_requests::table
+            .filter(join_requests::mission_id.eq(mission_id))
+            .filter(join_requests::status.eq(status))
+            .select(JoinRequestEntity::as_select())
+            .order_by((join_requests::created_at.asc(), join_requests::id.asc()))
+            .load::<JoinRequestEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_join_requests_by_brawler(
+        &self,
+        brawler_id: i32,
+        status: JoinRequestStatuses,
+    ) -> Result<Vec<JoinRequestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = join_requests::table
+            .filter(join_requests::brawler_id.eq(brawler_id))
+            .filter(join_requests::status.eq(status))
+            .select(JoinRequestEntity::as_select())
+            .order_by((join_requests::created_at.desc(), join_requests::id.desc()))
+            .load::<JoinRequestEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn accept_join_request(&self, request_id: i32, crew_capacity: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            // Locking the request keeps a withdraw racing this accept from both succeeding
+            let request = join_requests::table
+                .filter(join_requests::id.eq(request_id))
+                .filter(join_requests::status.eq(JoinRequestStatuses::Pending))
+                .select(JoinRequestEntity::as_select())
+                .for_update()
+                .first::<JoinRequestEntity>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Join request is no longer pending"))?;
+
+            join_crew(
+                conn,
+                CrewMemberShips {
+                    mission_id: request.mission_id,
+                    brawler_id: request.brawler_id,
+                },
+                crew_capacity,
+            )?;
+
+            update(join_requests::table)
+                .filter(join_requests::id.eq(request_id))
+                .set((
+                    join_requests::status.eq(JoinRequestStatuses::Accepted),
+                    join_requests::decided_at.eq(now),
+                ))
+                .execute(conn)?;
+            Ok(())
+        })
+    }
+
+    async fn close_join_request(&self, request_id: i32, status: JoinRequestStatuses) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let updated = update(join_requests::table)
+            .filter(join_requests::id.eq(request_id))
+            .filter(join_requests::status.eq(JoinRequestStatuses::Pending))
+            .set((
+                join_requests::status.eq(status),
+                join_requests::decided_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+        if updated == 0 {
+            return Err(anyhow::anyhow!("Join request is no longer pending"));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use diesel::{
+    Connection, ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl, SelectableHelper, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            crew_memberships::CrewMemberShips,
+            mission_invitations::{AddMissionInvitationEntity, MissionInvitationEntity},
+        },
+        repositories::mission_invitations::MissionInvitationRepository,
+        value_objects::mission_invitation_model::{InvitationStatuses, MissionInvitationModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::crew_operation::join_crew,
+        schema::{mission_invitations, missions},
+    },
+};
+
+pub struct MissionInvitationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionInvitationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionInvitationRepository for MissionInvitationPostgres {
+    async fn add(&self, add_mission_invitation_entity: AddMissionInvitationEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_invitations::table)
+            .values(add_mission_invitation_entity)
+            .returning(mission_invitations::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, invitation_id: i32) -> Result<MissionInvitationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_invitations::table
+            .filter(mission_invitations::id.eq(invitation_id))
+            .select(MissionInvitationEntity::as_select())
+            .first::<MissionInvitationEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Invitation not found"))?;
+        Ok(result)
+    }
+
+    async fn get_by_brawler(
+        &self,
+        brawler_id: i32,
+        status: InvitationStatuses,
+    ) -> Result<Vec<MissionInvitationModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_invitations::table
+            .inner_join(missions::table)
+            .filter(mission_invitations::brawler_id.eq(brawler_id))
+            .filter(mission_invitations::status.eq(status))
+            .filter(missions::deleted_at.is_null())
+            .select((MissionInvitationEntity::as_select(), missions::name))
+            .order_by((mission_invitations::created_at.desc(), mission_invitations::id.desc()))
+            .load::<(MissionInvitationEntity, String)>(&mut conn)?
+            .into_iter()
+            .map(|(invitation, mission_name)| invitation.to_model(mission_name))
+            .collect();
+        Ok(result)
+    }
+
+    async fn get_by_mission(
+        &self,
+        mission_id: i32,
+        status: InvitationStatuses,
+    ) -> Result<Vec<MissionInvitationModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_invitations::table
+            .inner_join(missions::table)
+            .filter(mission_invitations::mission_id.eq(mission_id))
+            .filter(mission_invitations::status.eq(status))
+            .select((MissionInvitationEntity::as_select(), missions::name))
+            .order_by((mission_invitations::created_at.asc(), mission_invitations::id.asc()))
+            .load::<(MissionInvitationEntity, String)>(&mut conn)?
+            .into_iter()
+            .map(|(invitation, mission_name)| invitation.to_model(mission_name))
+            .collect();
+        Ok(result)
+    }
+
+    async fn accept(&self, invitation_id: i32, crew_capacity: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            // Locking the invitation keeps a decline racing this accept from both succeeding
+            let invitation = mission_invitations::table
+                .filter(mission_invitations::id.eq(invitation_id))
+                .filter(mission_invitations::status.eq(InvitationStatuses::Pending))
+                .select(MissionInvitationEntity::as_select())
+                .for_update()
+                .first::<MissionInvitationEntity>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Invitation is no longer pending"))?;
+            if invitation.expires_at <= Utc::now().naive_utc() {
+                return Err(anyhow::anyhow!("Invitation has expired"));
+            }
+
+            join_crew(
+                conn,
+                CrewMemberShips {
+                    mission_id: invitation.mission_id,
+                    brawler_id: invitation.brawler_id,
+                },
+                crew_capacity,
+            )?;
+
+            update(mission_invitations::table)
+                .filter(mission_invitations::id.eq(invitation_id))
+                .set((
+                    mission_invitations::status.eq(InvitationStatuses::Accepted),
+                    mission_invitations::decided_at.eq(Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+            Ok(())
+        })
+    }
+
+    async fn close(&self, invitation_id: i32, status: InvitationStatuses) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let updated = update(mission_invitations::table)
+            .filter(mission_invitations::id.eq(invitation_id))
+            .filter(mission_invitations::status.eq(InvitationStatuses::Pending))
+            .set((
+                mission_invitations::status.eq(status),
+                mission_invitations::decided_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+        if updated == 0 {
+            return Err(anyhow::anyhow!("Invitation is no longer pending"));
+        }
+        Ok(())
+    }
+
+    async fn expire_lapsed(&self, expired_before: NaiveDateTime) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = update(mission_invitations::table)
+            .filter(mission_invitations::status.eq(InvitationStatuses::Pending))
+            .filter(mission_invitations::expires_at.le(expired_before))
+            .set((
+                mission_invitations::status.eq(InvitationStatuses::Expired),
+                mission_invitations::decided_at.eq(mission_invitations::expires_at.nullable()),
+            ))
+            .execute(&mut conn)?;
+        Ok(result)
+    }
+}
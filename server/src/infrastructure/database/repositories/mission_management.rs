@@ -1,13 +1,26 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
+        entities::{
+            mission_lanes::AddMissionLaneEntity,
+            mission_status_history::AddMissionStatusHistoryEntity,
+            mission_tags::{AddMissionTagEntity, AddTagEntity},
+            missions::{AddMissionEntity, EditMissionEntity},
+        },
         repositories::mission_management::MissionManagementRepository,
+        value_objects::{mission_lane_model::LaneSlotModel, mission_statuses::MissionStatuses},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_lanes, mission_status_history, mission_tags, missions, tags},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, delete, dsl::now,
+    dsl::update, insert_into,
+};
 use std::sync::Arc;
 
 pub struct MissionManagementPostgres {
@@ -20,25 +33,132 @@ impl MissionManagementPostgres {
     }
 }
 
+/// Inserts the mission with its first status history row.
+pub fn insert_mission(conn: &mut PgConnection, add_mission_entity: AddMissionEntity) -> Result<i32> {
+    let chief_id = add_mission_entity.chief_id;
+    let to_status = add_mission_entity.status;
+
+    let mission_id = insert_into(missions::table)
+        .values(add_mission_entity)
+        .returning(missions::id)
+        .get_result::<i32>(conn)?;
+
+    insert_into(mission_status_history::table)
+        .values(AddMissionStatusHistoryEntity {
+            mission_id,
+            from_status: None,
+            to_status,
+            actor_id: Some(chief_id),
+        })
+        .execute(conn)?;
+
+    Ok(mission_id)
+}
+
+/// Swaps the mission's tags for `tag_names`, creating tags that don't exist yet.
+fn replace_mission_tags(conn: &mut PgConnection, mission_id: i32, tag_names: &[String]) -> Result<()> {
+    delete(mission_tags::table.filter(mission_tags::mission_id.eq(mission_id))).execute(conn)?;
+    if tag_names.is_empty() {
+        return Ok(());
+    }
+
+    insert_into(tags::table)
+        .values(
+            tag_names
+                .iter()
+                .map(|name| AddTagEntity { name: name.clone() })
+                .collect::<Vec<_>>(),
+        )
+        .on_conflict(tags::name)
+        .do_nothing()
+        .execute(conn)?;
+
+    let tag_ids = tags::table
+        .filter(tags::name.eq_any(tag_names))
+        .select(tags::id)
+        .load::<i32>(conn)?;
+
+    insert_into(mission_tags::table)
+        .values(
+            tag_ids
+                .into_iter()
+                .map(|tag_id| AddMissionTagEntity { mission_id, tag_id })
+                .collect::<Vec<_>>(),
+        )
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Swaps the mission's declared lanes for `lanes`.
+fn replace_mission_lanes(conn: &mut PgConnection, mission_id: i32, lanes: &[LaneSlotModel]) -> Result<()> {
+    delete(mission_lanes::table.filter(mission_lanes::mission_id.eq(mission_id))).execute(conn)?;
+    if lanes.is_empty() {
+        return Ok(());
+    }
+
+    insert_into(mission_lanes::table)
+        .values(
+            lanes
+                .iter()
+                .map(|lane_slot| AddMissionLaneEntity {
+                    mission_id,
+                    lane: lane_slot.lane,
+                    slots: lane_slot.slots,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .execute(conn)?;
+
+    Ok(())
+}
+
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
+    async fn add(
+        &self,
+        add_mission_entity: AddMissionEntity,
+        tags: Vec<String>,
+        lanes: Vec<LaneSlotModel>,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = insert_into(missions::table)
-            .values(add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let mission_id = insert_mission(conn, add_mission_entity)?;
+            replace_mission_tags(conn, mission_id, &tags)?;
+            replace_mission_lanes(conn, mission_id, &lanes)?;
+
+            Ok(mission_id)
+        })?;
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: EditMissionEntity,
+        tags: Option<Vec<String>>,
+        lanes: Option<Vec<LaneSlotModel>>,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let mission_id = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .filter(missions::deleted_at.is_null())
+                .set(edit_mission_entity)
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+
+            if let Some(tags) = tags {
+                replace_mission_tags(conn, mission_id, &tags)?;
+            }
+            if let Some(lanes) = lanes {
+                replace_mission_lanes(conn, mission_id, &lanes)?;
+            }
+
+            Ok(mission_id)
+        })?;
         Ok(result)
     }
 
@@ -56,4 +176,22 @@ impl MissionManagementRepository for MissionManagementPostgres {
 
         Ok(())
     }
+
+    async fn archive_finished(&self, finished_before: NaiveDateTime) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = update(missions::table)
+            .filter(missions::status.eq_any([
+                MissionStatuses::Completed,
+                MissionStatuses::Failed,
+                MissionStatuses::Cancelled,
+            ]))
+            .filter(missions::archived_at.is_null())
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::finished_at.le(finished_before))
+            .set(missions::archived_at.eq(now))
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
 }
@@ -1,15 +1,25 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Ok, Result};
+use anyhow::{Context, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use chrono::{NaiveDateTime, Utc};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, RunQueryDsl, SelectableHelper,
+    dsl::{delete, insert_into, update},
+};
 
 use crate::{
     domain::{
+        entities::{mission_status_history::AddMissionStatusHistoryEntity, missions::MissionEntity},
         repositories::mission_operation::MissionOperationRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        value_objects::{
+            mission_statuses::MissionStatuses, mission_transition::MissionTransitionModel,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_status_history, mission_waitlist, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -19,59 +29,108 @@ impl MissionOperationPostgres {
     pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
         Self { db_pool }
     }
+}
 
-    async fn set_status(
-        &self,
-        mission_id: i32,
-        chief_id: i32,
-        status: MissionStatuses,
-    ) -> Result<i32> {
+#[async_trait]
+impl MissionOperationRepository for MissionOperationPostgres {
+    async fn transition(&self, mission_id: i32, transition: MissionTransitionModel) -> Result<i32> {
         let db_pool = Arc::clone(&self.db_pool);
-        let status_string = status.to_string();
         let id = tokio::task::spawn_blocking(move || -> Result<i32> {
             let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
-            update(missions::table)
-                .filter(missions::id.eq(mission_id))
-                .filter(missions::chief_id.eq(chief_id))
-                .filter(missions::deleted_at.is_null())
-                .set((
-                    missions::status.eq(status_string),
-                    missions::updated_at.eq(diesel::dsl::now),
-                ))
-                .returning(missions::id)
-                .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
+            conn.transaction::<i32, anyhow::Error, _>(|conn| {
+                let finished = matches!(
+                    transition.to,
+                    MissionStatuses::Completed | MissionStatuses::Failed | MissionStatuses::Cancelled
+                );
+
+                // Matching on the previous status keeps concurrent transitions from both winning
+                let id = update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::status.eq(transition.from))
+                    .filter(missions::deleted_at.is_null())
+                    .set((
+                        missions::status.eq(transition.to),
+                        missions::updated_at.eq(diesel::dsl::now),
+                        // A status change brings an archived mission back into listings
+                        missions::archived_at.eq(None::<NaiveDateTime>),
+                        // Reopening a failed mission clears it again
+                        missions::finished_at.eq(finished.then(|| Utc::now().naive_utc())),
+                    ))
+                    .returning(missions::id)
+                    .get_result::<i32>(conn)
+                    .optional()
+                    .context("Failed to execute mission update query")?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Mission is no longer {}", transition.from)
+                    })?;
+
+                insert_into(mission_status_history::table)
+                    .values(AddMissionStatusHistoryEntity {
+                        mission_id,
+                        from_status: Some(transition.from),
+                        to_status: transition.to,
+                        actor_id: transition.actor_id,
+                    })
+                    .execute(conn)
+                    .context("Failed to record mission status history")?;
+
+                // Nobody can be promoted into a finished mission
+                if matches!(transition.to, MissionStatuses::Completed | MissionStatuses::Cancelled) {
+                    delete(mission_waitlist::table)
+                        .filter(mission_waitlist::mission_id.eq(mission_id))
+                        .execute(conn)
+                        .context("Failed to clear the mission waitlist")?;
+                }
+
+                Ok(id)
+            })
         })
         .await??;
 
         Ok(id)
     }
-}
-
-#[async_trait]
-impl MissionOperationRepository for MissionOperationPostgres {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::InProgress)
-            .await?;
 
-        Ok(result)
-    }
+    async fn claim_due_reminders(
+        &self,
+        now: NaiveDateTime,
+        remind_before: NaiveDateTime,
+    ) -> Result<Vec<MissionEntity>> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let result = tokio::task::spawn_blocking(move || -> Result<Vec<MissionEntity>> {
+            let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Completed)
-            .await?;
+            // Marking and returning in one statement keeps overlapping sweeps from reminding twice
+            update(missions::table)
+                .filter(missions::status.eq_any([MissionStatuses::Open, MissionStatuses::InProgress]))
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::due_reminded_at.is_null())
+                .filter(missions::due_date.gt(now))
+                .filter(missions::due_date.le(remind_before))
+                .set(missions::due_reminded_at.eq(now))
+                .returning(MissionEntity::as_returning())
+                .get_results::<MissionEntity>(&mut conn)
+                .context("Failed to claim due-date reminders")
+        })
+        .await??;
 
         Ok(result)
     }
 
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Failed)
-            .await?;
+    async fn release_due_reminder(&self, mission_id: i32) -> Result<()> {
+        let db_pool = Arc::clone(&self.db_pool);
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
-        Ok(result)
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set(missions::due_reminded_at.eq(None::<NaiveDateTime>))
+                .execute(&mut conn)
+                .context("Failed to release due-date reminder")?;
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
     }
 }
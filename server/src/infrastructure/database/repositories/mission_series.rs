@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::now, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            mission_series::{AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity},
+            missions::AddMissionEntity,
+        },
+        repositories::mission_series::MissionSeriesRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::mission_management::insert_mission,
+        schema::mission_series,
+    },
+};
+
+pub struct MissionSeriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionSeriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionSeriesRepository for MissionSeriesPostgres {
+    async fn add(&self, add_mission_series_entity: AddMissionSeriesEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_series::table)
+            .values(add_mission_series_entity)
+            .returning(mission_series::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, series_id: i32) -> Result<MissionSeriesEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::id.eq(series_id))
+            .select(MissionSeriesEntity::as_select())
+            .first::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_by_chief(&self, chief_id: i32) -> Result<Vec<MissionSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::chief_id.eq(chief_id))
+            .select(MissionSeriesEntity::as_select())
+            .order_by((mission_series::is_active.desc(), mission_series::created_at.desc()))
+            .load::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        series_id: i32,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .set((
+                edit_mission_series_entity,
+                mission_series::updated_at.eq(now),
+            ))
+            .returning(mission_series::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn stop(&self, series_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .set((
+                mission_series::is_active.eq(false),
+                mission_series::next_occurrence_on.eq(None::<NaiveDate>),
+                mission_series::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_due(&self, today: NaiveDate) -> Result<Vec<MissionSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::is_active.eq(true))
+            .filter(mission_series::next_occurrence_on.le(today))
+            .select(MissionSeriesEntity::as_select())
+            .order_by(mission_series::id.asc())
+            .load::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn advance(
+        &self,
+        series_id: i32,
+        scheduled_on: NaiveDate,
+        next_occurrence_on: Option<NaiveDate>,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        advance_series(&mut conn, series_id, scheduled_on, None, next_occurrence_on)
+    }
+
+    async fn create_occurrence(
+        &self,
+        series_id: i32,
+        scheduled_on: NaiveDate,
+        occurrence_on: NaiveDate,
+        next_occurrence_on: Option<NaiveDate>,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = conn.transaction::<Option<i32>, anyhow::Error, _>(|conn| {
+            if !advance_series(
+                conn,
+                series_id,
+                scheduled_on,
+                Some(occurrence_on),
+                next_occurrence_on,
+            )? {
+                return Ok(None);
+            }
+            Ok(Some(insert_mission(conn, add_mission_entity)?))
+        })?;
+        Ok(result)
+    }
+}
+
+/// Moves the series past `scheduled_on`, counting `occurrence_on` when one is created.
+fn advance_series(
+    conn: &mut PgConnection,
+    series_id: i32,
+    scheduled_on: NaiveDate,
+    occurrence_on: Option<NaiveDate>,
+    next_occurrence_on: Option<NaiveDate>,
+) -> Result<bool> {
+    // Matching on the scheduled date makes overlapping sweeps create each occurrence once
+    let target = update(mission_series::table)
+        .filter(mission_series::id.eq(series_id))
+        .filter(mission_series::is_active.eq(true))
+        .filter(mission_series::next_occurrence_on.eq(scheduled_on));
+    let changes = (
+        mission_series::next_occurrence_on.eq(next_occurrence_on),
+        mission_series::is_active.eq(next_occurrence_on.is_some()),
+        mission_series::updated_at.eq(now),
+    );
+
+    let updated = match occurrence_on {
+        Some(occurrence_on) => target
+            .set((
+                changes,
+                mission_series::occurrences_created.eq(mission_series::occurrences_created + 1),
+                mission_series::last_occurrence_on.eq(occurrence_on),
+            ))
+            .execute(conn)?,
+        None => target.set(changes).execute(conn)?,
+    };
+
+    Ok(updated > 0)
+}
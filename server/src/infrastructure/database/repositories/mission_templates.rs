@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    SelectableHelper, dsl::{delete, now}, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::mission_templates::{
+            AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+        },
+        repositories::mission_templates::MissionTemplateRepository,
+        value_objects::mission_template_model::TemplateScopes,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_templates},
+};
+
+pub struct MissionTemplatePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionTemplatePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionTemplateRepository for MissionTemplatePostgres {
+    async fn add(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_templates::table)
+            .values(add_mission_template_entity)
+            .returning(mission_templates::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, template_id: i32) -> Result<MissionTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_templates::table
+            .filter(mission_templates::id.eq(template_id))
+            .select(MissionTemplateEntity::as_select())
+            .first::<MissionTemplateEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_visible(
+        &self,
+        brawler_id: i32,
+        scope: Option<TemplateScopes>,
+    ) -> Result<Vec<MissionTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = mission_templates::table.into_boxed();
+        query = match scope {
+            Some(TemplateScopes::Mine) => query.filter(mission_templates::owner_id.eq(brawler_id)),
+            Some(TemplateScopes::Global) => query.filter(mission_templates::owner_id.is_null()),
+            None => query.filter(
+                mission_templates::owner_id
+                    .eq(brawler_id)
+                    .or(mission_templates::owner_id.is_null()),
+            ),
+        };
+
+        // Own templates first, then globals
+        let result = query
+            .select(MissionTemplateEntity::as_select())
+            .order_by((
+                mission_templates::owner_id.is_null().asc(),
+                mission_templates::name.asc(),
+            ))
+            .load::<MissionTemplateEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn count_owned(&self, owner_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_templates::table
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        edit_mission_template_entity: EditMissionTemplateEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = update(mission_templates::table)
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .set((
+                edit_mission_template_entity,
+                mission_templates::updated_at.eq(now),
+            ))
+            .returning(mission_templates::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(mission_templates::table)
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn upsert_global(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<bool, anyhow::Error, _>(|conn| {
+            let existing = mission_templates::table
+                .filter(mission_templates::owner_id.is_null())
+                .filter(mission_templates::name.eq(&add_mission_template_entity.name))
+                .select(mission_templates::id)
+                .for_update()
+                .first::<i32>(conn)
+                .optional()?;
+
+            match existing {
+                Some(template_id) => {
+                    update(mission_templates::table)
+                        .filter(mission_templates::id.eq(template_id))
+                        .set((
+                            mission_templates::description
+                                .eq(&add_mission_template_entity.description),
+                            mission_templates::difficulty
+                                .eq(&add_mission_template_entity.difficulty),
+                            mission_templates::max_crew.eq(add_mission_template_entity.max_crew),
+                            mission_templates::due_in_hours
+                                .eq(add_mission_template_entity.due_in_hours),
+                            mission_templates::updated_at.eq(now),
+                        ))
+                        .execute(conn)?;
+                    Ok(false)
+                }
+                None => {
+                    insert_into(mission_templates::table)
+                        .values(&add_mission_template_entity)
+                        .execute(conn)?;
+                    Ok(true)
+                }
+            }
+        })
+    }
+}
@@ -2,17 +2,32 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use chrono::NaiveDateTime;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, PgSortExpressionMethods,
+    PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{Filter, GroupBy, IntoBoxed, IsNull, LeftJoin, Select, case_when, sql},
+    pg::Pg,
+    sql_types::{Array, BigInt, Bool, Float, Int4, Text},
+};
 
 use crate::{
     domain::{
-        entities::missions::MissionEntity,
+        entities::{mission_status_history::MissionStatusHistoryEntity, missions::MissionEntity},
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+        value_objects::{
+            brawler_model::BrawlerModel, mission_filter::MissionFilter,
+            mission_lane_model::MissionLaneModel, mission_model::MissionSnippetModel,
+            mission_sorts::{MissionSorts, SortDirections},
+            mission_statuses::MissionStatuses,
+            mission_tag_model::{TagCountModel, TagMatches},
+        },
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
-        schema::{crew_memberships, missions},
+        schema::{
+            brawlers, crew_memberships, mission_status_history, mission_tags, missions, tags,
+        },
     },
 };
 
@@ -26,17 +41,152 @@ impl MissionViewingPostgres {
     }
 }
 
+type FilteredMissions<'a> = IntoBoxed<
+    'a,
+    Select<
+        GroupBy<
+            Filter<LeftJoin<missions::table, crew_memberships::table>, IsNull<missions::deleted_at>>,
+            missions::id,
+        >,
+        missions::id,
+    >,
+    Pg,
+>;
+
+/// Ids of the missions matching every filter in `mission_filter`, grouped so the crew count
+/// can be selected or compared against. Callers pick the columns, order and page.
+fn filtered_missions<'a>(mission_filter: &MissionFilter) -> Result<FilteredMissions<'a>> {
+    let crew_count = diesel::dsl::count(crew_memberships::brawler_id.nullable());
+
+    let mut query = missions::table
+        .left_join(crew_memberships::table)
+        .filter(missions::deleted_at.is_null())
+        .group_by(missions::id)
+        .select(missions::id)
+        .into_boxed();
+
+    if let Some(chief_id) = mission_filter.chief_id {
+        query = query.filter(missions::chief_id.eq(chief_id));
+    }
+    if let Some(series_id) = mission_filter.series_id {
+        query = query.filter(missions::series_id.eq(series_id));
+    }
+
+    let statuses = mission_filter.statuses()?;
+    if !statuses.is_empty() {
+        query = query.filter(missions::status.eq_any(statuses));
+    }
+    let difficulties = mission_filter.difficulties()?;
+    if !difficulties.is_empty() {
+        query = query.filter(missions::difficulty.eq_any(difficulties));
+    }
+    if let Some(due_after) = mission_filter.due_after {
+        query = query.filter(missions::due_date.ge(due_after));
+    }
+    if let Some(due_before) = mission_filter.due_before {
+        query = query.filter(missions::due_date.le(due_before));
+    }
+    if mission_filter.member.unwrap_or(false)
+        && let Some(viewer_id) = mission_filter.viewer_id
+    {
+        // crew_memberships is already joined for the crew count
+        let viewer_crew = diesel::alias!(crew_memberships as viewer_crew);
+        query = query.filter(
+            missions::id.eq_any(
+                viewer_crew
+                    .filter(viewer_crew.field(crew_memberships::brawler_id).eq(viewer_id))
+                    .select(viewer_crew.field(crew_memberships::mission_id)),
+            ),
+        );
+    }
+    if mission_filter.eligible.unwrap_or(false)
+        && let (Some(viewer_rank), Some(viewer_total_points)) =
+            (mission_filter.viewer_rank, mission_filter.viewer_total_points)
+    {
+        // rank_tier sorts in tier order, so the bounds compare directly
+        query = query
+            .filter(missions::min_rank.is_null().or(missions::min_rank.le(viewer_rank)))
+            .filter(missions::max_rank.is_null().or(missions::max_rank.ge(viewer_rank)))
+            .filter(
+                missions::min_total_points
+                    .is_null()
+                    .or(missions::min_total_points.le(viewer_total_points)),
+            );
+    }
+
+    let has_open_slots = mission_filter.has_open_slots.unwrap_or(false);
+    if has_open_slots {
+        query = query.filter(
+            missions::status.eq_any([MissionStatuses::Open, MissionStatuses::Failed]),
+        );
+    }
+    if has_open_slots || mission_filter.not_full.unwrap_or(false) {
+        // Same as crew_count < LEAST(max_crew, crew_ceiling)
+        let crew_ceiling = mission_filter.crew_ceiling.map_or(i64::MAX, i64::from);
+        query = query.having(
+            crew_count
+                .lt(missions::max_crew.cast::<BigInt>())
+                .and(crew_count.lt(crew_ceiling)),
+        );
+    }
+    if !mission_filter.include_archived.unwrap_or(false) {
+        query = query.filter(missions::archived_at.is_null());
+    }
+    if let Some(name) = &mission_filter.name {
+        query = query.filter(missions::name.ilike(format!("%{}%", name)));
+    };
+
+    // websearch syntax: quoted phrases, OR and -excluded words. The 'simple' config has to
+    // match the one search_vector is generated with
+    if let Some(search_query) = mission_filter.search_query() {
+        query = query.filter(
+            sql::<Bool>("missions.search_vector @@ websearch_to_tsquery('simple', ")
+                .bind::<Text, _>(search_query.to_string())
+                .sql(")"),
+        );
+    }
+
+    let tag_names = mission_filter.tag_names()?;
+    if !tag_names.is_empty() {
+        match mission_filter.tag_match.unwrap_or_default() {
+            TagMatches::Any => {
+                query = query.filter(
+                    missions::id.eq_any(
+                        mission_tags::table
+                            .inner_join(tags::table)
+                            .filter(tags::name.eq_any(tag_names))
+                            .select(mission_tags::mission_id),
+                    ),
+                );
+            }
+            TagMatches::All => {
+                for tag_name in tag_names {
+                    query = query.filter(
+                        missions::id.eq_any(
+                            mission_tags::table
+                                .inner_join(tags::table)
+                                .filter(tags::name.eq(tag_name))
+                                .select(mission_tags::mission_id),
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(query)
+}
+
 #[async_trait]
 impl MissionViewingRepository for MissionViewingPostgres {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let value = crew_memberships::table
+        let count = crew_memberships::table
             .filter(crew_memberships::mission_id.eq(mission_id))
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = i64::try_from(value)?;
         Ok(count)
     }
 
@@ -54,43 +204,83 @@ impl MissionViewingRepository for MissionViewingPostgres {
     async fn get_all(
         &self,
         mission_filter: &MissionFilter,
+        limit: i64,
+        offset: i64,
     ) -> Result<Vec<(MissionEntity, i64)>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
+        let crew_count = diesel::dsl::count(crew_memberships::brawler_id.nullable());
 
-        let mut query = missions::table
-            .left_join(crew_memberships::table)
-            .filter(missions::deleted_at.is_null())
-            .into_boxed();
-
-        if let Some(chief_id) = mission_filter.chief_id {
-            query = query.filter(missions::chief_id.eq(chief_id));
-        }
+        let mut query = filtered_missions(mission_filter)?
+            .select((MissionEntity::as_select(), crew_count));
 
-        if let Some(status) = &mission_filter.status {
-            let status_string = status.to_string();
-            query = query.filter(missions::status.eq(status_string));
-        };
-        if let Some(name) = &mission_filter.name {
-            query = query.filter(missions::name.ilike(format!("%{}%", name)));
-        };
-
-        if let (Some(page), Some(limit)) = (mission_filter.page, mission_filter.limit) {
-            let offset = (page - 1) * limit;
-            query = query.limit(limit).offset(offset);
+        // An explicit sort wins over search relevance
+        if let Some(sort_by) = mission_filter.sort_by {
+            let sort_dir = mission_filter
+                .sort_dir
+                .unwrap_or_else(|| sort_by.default_direction());
+            let difficulty_rank = case_when::<_, _, Int4>(missions::difficulty.eq("EASY"), 1)
+                .when(missions::difficulty.eq("NORMAL"), 2)
+                .otherwise(3);
+            query = match (sort_by, sort_dir) {
+                (MissionSorts::Recent, SortDirections::Asc) => {
+                    query.order_by(missions::created_at.asc())
+                }
+                (MissionSorts::Recent, SortDirections::Desc) => {
+                    query.order_by(missions::created_at.desc())
+                }
+                (MissionSorts::DueDate, SortDirections::Asc) => {
+                    query.order_by(missions::due_date.asc().nulls_last())
+                }
+                (MissionSorts::DueDate, SortDirections::Desc) => {
+                    query.order_by(missions::due_date.desc().nulls_last())
+                }
+                (MissionSorts::BasePoints, SortDirections::Asc) => {
+                    query.order_by(missions::base_points.asc())
+                }
+                (MissionSorts::BasePoints, SortDirections::Desc) => {
+                    query.order_by(missions::base_points.desc())
+                }
+                (MissionSorts::Difficulty, SortDirections::Asc) => {
+                    query.order_by(difficulty_rank.asc())
+                }
+                (MissionSorts::Difficulty, SortDirections::Desc) => {
+                    query.order_by(difficulty_rank.desc())
+                }
+                (MissionSorts::CrewCount, SortDirections::Asc) => query.order_by(crew_count.asc()),
+                (MissionSorts::CrewCount, SortDirections::Desc) => {
+                    query.order_by(crew_count.desc())
+                }
+            };
+        } else if let Some(search_query) = mission_filter.search_query() {
+            query = query.order_by(
+                sql::<Float>("ts_rank(missions.search_vector, websearch_to_tsquery('simple', ")
+                    .bind::<Text, _>(search_query.to_string())
+                    .sql("))")
+                    .desc(),
+            );
         }
 
         let value = query
-            .group_by(missions::id)
-            .select((
-                MissionEntity::as_select(),
-                diesel::dsl::count(crew_memberships::brawler_id),
-            ))
-            .order_by(missions::created_at.desc())
+            .then_order_by(missions::created_at.desc())
+            .then_order_by(missions::id.desc())
+            .limit(limit)
+            .offset(offset)
             .load::<(MissionEntity, i64)>(&mut conn)?;
 
         Ok(value)
     }
 
+    async fn count_all(&self, mission_filter: &MissionFilter) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total = missions::table
+            .filter(missions::id.eq_any(filtered_missions(mission_filter)?))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(total)
+    }
+
     async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
@@ -100,8 +290,12 @@ impl MissionViewingRepository for MissionViewingPostgres {
                 b.display_name,
                 COALESCE(b.avatar_url, '') AS avatar_url,
                 COALESCE(s.success_count, 0) AS mission_success_count, 
+                r.rating_average,
+                COALESCE(r.rating_count, 0) AS rating_count,
+                COALESCE(mvp.mvp_count, 0) AS mvp_count,
                 COALESCE(j.joined_count, 0) AS mission_joined_count,
-                b.total_points
+                b.total_points,
+                cm.lane
             FROM 
                 crew_memberships cm
             INNER JOIN 
@@ -130,6 +324,27 @@ impl MissionViewingRepository for MissionViewingPostgres {
                     GROUP BY 
                         cm3.brawler_id
                 ) j ON j.brawler_id = b.id
+            LEFT JOIN 
+                (
+                    SELECT 
+                        mr.ratee_id, 
+                        ROUND(AVG(mr.score), 2)::FLOAT8 AS rating_average,
+                        COUNT(*) AS rating_count
+                    FROM 
+                        mission_ratings mr
+                    GROUP BY 
+                        mr.ratee_id
+                ) r ON r.ratee_id = b.id
+            LEFT JOIN 
+                (
+                    SELECT 
+                        mm.brawler_id, 
+                        COUNT(*) AS mvp_count
+                    FROM 
+                        mission_mvps mm
+                    GROUP BY 
+                        mm.brawler_id
+                ) mvp ON mvp.brawler_id = b.id
             WHERE 
                 cm.mission_id = $1
         "#;
@@ -152,26 +367,35 @@ impl MissionViewingRepository for MissionViewingPostgres {
             .count()
             .get_result(&mut conn)?;
 
+        // A chief who handed a mission over still created it, whenever they landed in its crew
+        let handed_over_count: i64 = crew_memberships::table
+            .inner_join(missions::table)
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .filter(crew_memberships::from_transfer.eq(true))
+            .filter(missions::created_at.ge(start_of_day))
+            .count()
+            .get_result(&mut conn)?;
+
         let joined_count: i64 = crew_memberships::table
             .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .filter(crew_memberships::from_transfer.eq(false))
             .filter(crew_memberships::joined_at.ge(start_of_day))
             .count()
             .get_result(&mut conn)?;
 
-        Ok(created_count + joined_count)
+        Ok(created_count + handed_over_count + joined_count)
     }
 
     async fn get_daily_earned_points(&self, brawler_id: i32) -> Result<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let now = chrono::Utc::now();
         let start_of_day = now.date_naive().and_hms_opt(0, 0, 0).unwrap();
-        let status_completed = "Completed".to_string();
 
         // As Chief
         let chief_points: Option<i64> = missions::table
             .filter(missions::chief_id.eq(brawler_id))
-            .filter(missions::status.eq(&status_completed))
-            .filter(missions::updated_at.ge(start_of_day))
+            .filter(missions::status.eq(MissionStatuses::Completed))
+            .filter(missions::finished_at.ge(start_of_day))
             .select(diesel::dsl::sum(missions::base_points))
             .get_result(&mut conn)?;
 
@@ -179,14 +403,24 @@ impl MissionViewingRepository for MissionViewingPostgres {
         let crew_points: Option<i64> = crew_memberships::table
             .inner_join(missions::table)
             .filter(crew_memberships::brawler_id.eq(brawler_id))
-            .filter(missions::status.eq(&status_completed))
-            .filter(missions::updated_at.ge(start_of_day))
+            .filter(missions::status.eq(MissionStatuses::Completed))
+            .filter(missions::finished_at.ge(start_of_day))
             .select(diesel::dsl::sum(missions::base_points))
             .get_result(&mut conn)?;
 
         Ok(chief_points.unwrap_or(0) + crew_points.unwrap_or(0))
     }
 
+    async fn get_total_points(&self, brawler_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select(brawlers::total_points)
+            .first::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
     async fn get_crew_ids(&self, mission_id: i32) -> Result<Vec<i32>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = crew_memberships::table
@@ -195,4 +429,120 @@ impl MissionViewingRepository for MissionViewingPostgres {
             .load::<i32>(&mut conn)?;
         Ok(result)
     }
-}
\ No newline at end of file
+
+    async fn get_overdue(&self, due_before: NaiveDateTime) -> Result<Vec<MissionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = missions::table
+            .filter(missions::status.eq_any([MissionStatuses::Open, MissionStatuses::InProgress]))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::due_date.lt(due_before))
+            .select(MissionEntity::as_select())
+            .order_by(missions::due_date.asc())
+            .load::<MissionEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_status_history(&self, mission_id: i32) -> Result<Vec<MissionStatusHistoryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_status_history::table
+            .filter(mission_status_history::mission_id.eq(mission_id))
+            .select(MissionStatusHistoryEntity::as_select())
+            .order_by(mission_status_history::id.asc())
+            .load::<MissionStatusHistoryEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_tags::table
+            .inner_join(tags::table)
+            .filter(mission_tags::mission_id.eq_any(mission_ids))
+            .select((mission_tags::mission_id, tags::name))
+            .order_by(tags::name.asc())
+            .load::<(i32, String)>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_popular_tags(&self, limit: i64) -> Result<Vec<TagCountModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mission_count = diesel::dsl::count(mission_tags::mission_id);
+        let result = tags::table
+            .inner_join(mission_tags::table.inner_join(missions::table))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::archived_at.is_null())
+            .group_by(tags::id)
+            .select((tags::name, mission_count))
+            .order_by((mission_count.desc(), tags::name.asc()))
+            .limit(limit)
+            .load::<(String, i64)>(&mut conn)?;
+
+        Ok(result
+            .into_iter()
+            .map(|(name, mission_count)| TagCountModel {
+                name,
+                mission_count,
+            })
+            .collect())
+    }
+
+    async fn get_lanes(&self, mission_ids: &[i32]) -> Result<Vec<MissionLaneModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                ml.mission_id,
+                ml.lane,
+                ml.slots,
+                COUNT(cm.brawler_id) AS filled
+            FROM
+                mission_lanes ml
+            LEFT JOIN
+                crew_memberships cm ON cm.mission_id = ml.mission_id AND cm.lane = ml.lane
+            WHERE
+                ml.mission_id = ANY($1)
+            GROUP BY
+                ml.mission_id, ml.lane, ml.slots
+            ORDER BY
+                ml.mission_id, ml.lane
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<Array<Int4>, _>(mission_ids)
+            .load::<MissionLaneModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_search_snippets(
+        &self,
+        mission_ids: &[i32],
+        search_query: &str,
+    ) -> Result<Vec<MissionSnippetModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // The text is HTML-escaped first so only the <mark> tags are markup
+        let sql = r#"
+            SELECT
+                m.id AS mission_id,
+                ts_headline(
+                    'simple',
+                    replace(replace(replace(
+                        m.name || coalesce(' - ' || m.description, ''),
+                        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                    websearch_to_tsquery('simple', $2),
+                    'StartSel=<mark>, StopSel=</mark>, MaxWords=25, MinWords=8, MaxFragments=2'
+                ) AS snippet
+            FROM
+                missions m
+            WHERE
+                m.id = ANY($1)
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<Array<Int4>, _>(mission_ids)
+            .bind::<Text, _>(search_query)
+            .load::<MissionSnippetModel>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -1,6 +1,15 @@
+pub mod achievements;
 pub mod brawlers;
 pub mod crew_operation;
 // pub mod diesel_transaction;
+pub mod mission_invitations;
 pub mod mission_management;
 pub mod mission_operation;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod mission_viewing;
+pub mod notifications;
+pub mod points_recompute;
+pub mod ratings;
+pub mod rewards;
+pub mod seasons;
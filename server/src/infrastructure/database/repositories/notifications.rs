@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, dsl::now, insert_into, update};
+
+use crate::{
+    domain::{
+        entities::notifications::{AddNotificationEntity, NotificationEntity},
+        repositories::notifications::NotificationRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::notifications},
+};
+
+pub struct NotificationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl NotificationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for NotificationPostgres {
+    async fn add_many(&self, notifications: Vec<AddNotificationEntity>) -> Result<usize> {
+        if notifications.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(notifications::table)
+            .values(&notifications)
+            .execute(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_by_brawler(
+        &self,
+        brawler_id: i32,
+        unread_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<NotificationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = notifications::table
+            .filter(notifications::brawler_id.eq(brawler_id))
+            .into_boxed();
+
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+
+        let result = query
+            .select(NotificationEntity::as_select())
+            .order_by((notifications::created_at.desc(), notifications::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .load::<NotificationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn mark_read(&self, brawler_id: i32, notification_id: Option<i32>) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = update(notifications::table)
+            .filter(notifications::brawler_id.eq(brawler_id))
+            .filter(notifications::read_at.is_null())
+            .into_boxed();
+
+        if let Some(notification_id) = notification_id {
+            query = query.filter(notifications::id.eq(notification_id));
+        }
+
+        let result = query
+            .set(notifications::read_at.eq(now))
+            .execute(&mut conn)?;
+        Ok(result)
+    }
+}
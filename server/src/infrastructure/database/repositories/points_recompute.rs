@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::{mission_ratings::MissionMvpEntity, point_ledger::AddPointLedgerEntity},
+        repositories::points_recompute::PointsRecomputeRepository,
+        value_objects::{
+            point_reasons::PointReasons,
+            points_recompute_model::{
+                BrawlerPointsModel, LedgerPointsModel, MissionParticipationModel, PointsDiffModel,
+            },
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, mission_mvps, point_ledger},
+    },
+};
+
+pub struct PointsRecomputePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PointsRecomputePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PointsRecomputeRepository for PointsRecomputePostgres {
+    async fn get_brawler_points(&self) -> Result<Vec<BrawlerPointsModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                b.id AS brawler_id,
+                b.username,
+                b.total_points
+            FROM
+                brawlers b
+            ORDER BY
+                b.id
+        "#;
+
+        let result = diesel::sql_query(sql).load::<BrawlerPointsModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_participations(&self) -> Result<Vec<MissionParticipationModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                p.brawler_id,
+                m.id AS mission_id,
+                p.is_chief,
+                m.status,
+                m.base_points,
+                m.due_date,
+                m.finished_at
+            FROM
+                missions m
+            INNER JOIN
+                (
+                    SELECT id AS mission_id, chief_id AS brawler_id, TRUE AS is_chief FROM missions
+                    UNION ALL
+                    SELECT mission_id, brawler_id, FALSE AS is_chief FROM crew_memberships
+                ) p ON p.mission_id = m.id
+            WHERE
+                m.deleted_at IS NULL
+                AND m.status IN ('Completed', 'Failed')
+            ORDER BY
+                m.finished_at, m.id
+        "#;
+
+        let result = diesel::sql_query(sql).load::<MissionParticipationModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_mvps(&self) -> Result<Vec<MissionMvpEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_mvps::table
+            .select(MissionMvpEntity::as_select())
+            .load::<MissionMvpEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_other_ledger_points(&self) -> Result<Vec<LedgerPointsModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = point_ledger::table
+            .filter(point_ledger::mission_id.is_null())
+            .filter(point_ledger::reason.ne(PointReasons::Recompute.to_string()))
+            .select((point_ledger::brawler_id, point_ledger::points, point_ledger::created_at))
+            .order_by((point_ledger::created_at.asc(), point_ledger::id.asc()))
+            .load::<(i32, i32, NaiveDateTime)>(&mut conn)?
+            .into_iter()
+            .map(|(brawler_id, points, created_at)| LedgerPointsModel {
+                brawler_id,
+                points,
+                created_at,
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    async fn apply(&self, diffs: Vec<PointsDiffModel>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            for diff in diffs {
+                // Bail out if points moved since the diff was computed; re-running picks it up
+                let updated = update(brawlers::table)
+                    .filter(brawlers::id.eq(diff.brawler_id))
+                    .filter(brawlers::total_points.eq(diff.current_points))
+                    .set(brawlers::total_points.eq(diff.expected_points))
+                    .execute(conn)?;
+                if updated == 0 {
+                    return Err(anyhow::anyhow!(
+                        "Points for {} changed during recompute, run it again",
+                        diff.username
+                    ));
+                }
+
+                diesel::sql_query(
+                    "UPDATE brawlers SET spendable_points = GREATEST(spendable_points + $2, 0) WHERE id = $1",
+                )
+                .bind::<diesel::sql_types::Int4, _>(diff.brawler_id)
+                .bind::<diesel::sql_types::Int4, _>(diff.diff)
+                .execute(conn)?;
+
+                insert_into(point_ledger::table)
+                    .values(&AddPointLedgerEntity {
+                        brawler_id: diff.brawler_id,
+                        points: diff.diff,
+                        reason: PointReasons::Recompute.to_string(),
+                        mission_id: None,
+                    })
+                    .execute(conn)?;
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper, dsl::now,
+    insert_into, upsert::excluded,
+};
+
+use crate::{
+    domain::{
+        entities::mission_ratings::{
+            AddMissionMvpEntity, AddMissionRatingEntity, AddMvpVoteEntity, MissionMvpEntity,
+            MissionRatingEntity,
+        },
+        repositories::ratings::RatingRepository,
+        value_objects::{
+            mission_statuses::MissionStatuses,
+            rating_model::{MvpTallyModel, RatingAggregateModel, RatingTagCountModel},
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_mvp_votes, mission_mvps, mission_ratings, missions},
+    },
+};
+
+pub struct RatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RatingRepository for RatingPostgres {
+    async fn upsert_rating(&self, add_rating_entity: AddMissionRatingEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(mission_ratings::table)
+            .values(&add_rating_entity)
+            .on_conflict((
+                mission_ratings::mission_id,
+                mission_ratings::rater_id,
+                mission_ratings::ratee_id,
+            ))
+            .do_update()
+            .set((
+                mission_ratings::score.eq(excluded(mission_ratings::score)),
+                mission_ratings::tags.eq(excluded(mission_ratings::tags)),
+                mission_ratings::updated_at.eq(now),
+            ))
+            .returning(mission_ratings::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_ratings_by_rater(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+    ) -> Result<Vec<MissionRatingEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_ratings::table
+            .filter(mission_ratings::mission_id.eq(mission_id))
+            .filter(mission_ratings::rater_id.eq(rater_id))
+            .select(MissionRatingEntity::as_select())
+            .order_by(mission_ratings::ratee_id.asc())
+            .load::<MissionRatingEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn upsert_mvp_vote(&self, add_vote_entity: AddMvpVoteEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(mission_mvp_votes::table)
+            .values(&add_vote_entity)
+            .on_conflict((mission_mvp_votes::mission_id, mission_mvp_votes::voter_id))
+            .do_update()
+            .set((
+                mission_mvp_votes::nominee_id.eq(excluded(mission_mvp_votes::nominee_id)),
+                mission_mvp_votes::created_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn get_mvp_vote(&self, mission_id: i32, voter_id: i32) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_mvp_votes::table
+            .filter(mission_mvp_votes::mission_id.eq(mission_id))
+            .filter(mission_mvp_votes::voter_id.eq(voter_id))
+            .select(mission_mvp_votes::nominee_id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_mvp_tally(&self, mission_id: i32) -> Result<Vec<MvpTallyModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Ties go to whoever reached their final vote count first
+        let sql = r#"
+            SELECT
+                nominee_id,
+                COUNT(*) AS votes
+            FROM
+                mission_mvp_votes
+            WHERE
+                mission_id = $1
+            GROUP BY
+                nominee_id
+            ORDER BY
+                votes DESC, MAX(created_at) ASC, nominee_id ASC
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(mission_id)
+            .load::<MvpTallyModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_mvp(&self, mission_id: i32) -> Result<Option<MissionMvpEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_mvps::table
+            .filter(mission_mvps::mission_id.eq(mission_id))
+            .select(MissionMvpEntity::as_select())
+            .first::<MissionMvpEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_unsettled_mvp_missions(&self, completed_before: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = missions::table
+            .inner_join(mission_mvp_votes::table)
+            .left_join(mission_mvps::table)
+            .filter(missions::status.eq(MissionStatuses::Completed))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::finished_at.le(completed_before))
+            .filter(mission_mvps::mission_id.nullable().is_null())
+            .select(missions::id)
+            .distinct()
+            .order_by(missions::id.asc())
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn award_mvp(&self, add_mvp_entity: AddMissionMvpEntity) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let inserted = insert_into(mission_mvps::table)
+            .values(&add_mvp_entity)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn get_aggregate(&self, brawler_id: i32) -> Result<RatingAggregateModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                (SELECT ROUND(AVG(score), 2)::FLOAT8 FROM mission_ratings WHERE ratee_id = $1) AS rating_average,
+                (SELECT COUNT(*) FROM mission_ratings WHERE ratee_id = $1) AS rating_count,
+                (SELECT COUNT(*) FROM mission_mvps WHERE brawler_id = $1) AS mvp_count
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<RatingAggregateModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_tag_counts(&self, brawler_id: i32) -> Result<Vec<RatingTagCountModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                tag,
+                COUNT(*) AS count
+            FROM
+                mission_ratings, UNNEST(tags) AS tag
+            WHERE
+                ratee_id = $1
+            GROUP BY
+                tag
+            ORDER BY
+                count DESC, tag ASC
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .load::<RatingTagCountModel>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::exists, insert_into, select, update,
+};
+
+use crate::{
+    domain::{
+        entities::rewards::{AddRewardRedemptionEntity, RewardEntity, RewardRedemptionEntity},
+        repositories::rewards::RewardRepository,
+        value_objects::reward_model::{OwnedRewardModel, RedemptionReceiptModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, reward_redemptions, rewards},
+    },
+};
+
+pub struct RewardPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RewardPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RewardRepository for RewardPostgres {
+    async fn get_catalog(&self, kind: Option<String>) -> Result<Vec<RewardEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = rewards::table
+            .filter(rewards::is_active.eq(true))
+            .into_boxed();
+
+        if let Some(kind) = kind {
+            query = query.filter(rewards::kind.eq(kind));
+        }
+
+        let result = query
+            .select(RewardEntity::as_select())
+            .order_by((rewards::cost.asc(), rewards::id.asc()))
+            .load::<RewardEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_owned(&self, brawler_id: i32) -> Result<Vec<OwnedRewardModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = reward_redemptions::table
+            .inner_join(rewards::table)
+            .filter(reward_redemptions::brawler_id.eq(brawler_id))
+            .select((
+                rewards::id,
+                rewards::code,
+                rewards::name,
+                rewards::kind,
+                rewards::value,
+                reward_redemptions::redeemed_at,
+            ))
+            .order_by(reward_redemptions::redeemed_at.asc())
+            .load::<OwnedRewardModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn redeem(&self, brawler_id: i32, reward_id: i32) -> Result<RedemptionReceiptModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<RedemptionReceiptModel, anyhow::Error, _>(|conn| {
+            // Lock the reward so concurrent redemptions can't oversell the stock
+            let reward = rewards::table
+                .filter(rewards::id.eq(reward_id))
+                .select(RewardEntity::as_select())
+                .for_update()
+                .first::<RewardEntity>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Reward not found"))?;
+
+            if !reward.is_active {
+                return Err(anyhow::anyhow!("{} is no longer available", reward.name));
+            }
+            if reward.stock == Some(0) {
+                return Err(anyhow::anyhow!("{} is out of stock", reward.name));
+            }
+
+            let already_owned = select(exists(
+                reward_redemptions::table
+                    .filter(reward_redemptions::brawler_id.eq(brawler_id))
+                    .filter(reward_redemptions::reward_id.eq(reward_id)),
+            ))
+            .get_result::<bool>(conn)?;
+            if already_owned {
+                return Err(anyhow::anyhow!("You already own {}", reward.name));
+            }
+
+            let spendable_points = update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .filter(brawlers::spendable_points.ge(reward.cost))
+                .set(brawlers::spendable_points.eq(brawlers::spendable_points - reward.cost))
+                .returning(brawlers::spendable_points)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Not enough points to redeem {} (cost: {})", reward.name, reward.cost)
+                })?;
+
+            if reward.stock.is_some() {
+                update(rewards::table)
+                    .filter(rewards::id.eq(reward_id))
+                    .set(rewards::stock.eq(rewards::stock - 1))
+                    .execute(conn)?;
+            }
+
+            let redemption = insert_into(reward_redemptions::table)
+                .values(&AddRewardRedemptionEntity {
+                    brawler_id,
+                    reward_id,
+                    cost: reward.cost,
+                })
+                .returning(RewardRedemptionEntity::as_returning())
+                .get_result::<RewardRedemptionEntity>(conn)?;
+
+            Ok(RedemptionReceiptModel {
+                redemption,
+                spendable_points,
+            })
+        })
+    }
+}
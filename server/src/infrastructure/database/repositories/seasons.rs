@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::now, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::seasons::{AddSeasonEntity, SeasonEntity},
+        repositories::seasons::SeasonRepository,
+        value_objects::season_model::SeasonStandingModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::seasons},
+};
+
+pub struct SeasonPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl SeasonPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl SeasonRepository for SeasonPostgres {
+    async fn get_all(&self) -> Result<Vec<SeasonEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .select(SeasonEntity::as_select())
+            .order_by(seasons::starts_at.desc())
+            .load::<SeasonEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, season_id: i32) -> Result<SeasonEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .filter(seasons::id.eq(season_id))
+            .select(SeasonEntity::as_select())
+            .first::<SeasonEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_current(&self) -> Result<Option<SeasonEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .filter(seasons::starts_at.le(now))
+            .filter(seasons::ends_at.gt(now))
+            .filter(seasons::archived_at.is_null())
+            .select(SeasonEntity::as_select())
+            .order_by(seasons::starts_at.desc())
+            .first::<SeasonEntity>(&mut conn)
+            .optional()?;
+        Ok(result)
+    }
+
+    async fn get_latest(&self) -> Result<Option<SeasonEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .select(SeasonEntity::as_select())
+            .order_by(seasons::ends_at.desc())
+            .first::<SeasonEntity>(&mut conn)
+            .optional()?;
+        Ok(result)
+    }
+
+    async fn get_ended_unarchived(&self) -> Result<Vec<SeasonEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .filter(seasons::ends_at.le(now))
+            .filter(seasons::archived_at.is_null())
+            .select(SeasonEntity::as_select())
+            .order_by(seasons::ends_at.asc())
+            .load::<SeasonEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn add(&self, add_season_entity: AddSeasonEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(seasons::table)
+            .values(add_season_entity)
+            .returning(seasons::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_live_standings(&self, season_id: i32, limit: i64) -> Result<Vec<SeasonStandingModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                ROW_NUMBER() OVER (ORDER BY sp.points DESC, sp.updated_at ASC, sp.brawler_id ASC) AS rank,
+                sp.brawler_id,
+                b.display_name,
+                COALESCE(b.avatar_url, '') AS avatar_url,
+                sp.points
+            FROM
+                season_points sp
+            INNER JOIN
+                brawlers b ON b.id = sp.brawler_id
+            WHERE
+                sp.season_id = $1
+            ORDER BY
+                rank
+            LIMIT $2
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(season_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<SeasonStandingModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_archived_standings(&self, season_id: i32, limit: i64) -> Result<Vec<SeasonStandingModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                ss.rank::BIGINT AS rank,
+                ss.brawler_id,
+                b.display_name,
+                COALESCE(b.avatar_url, '') AS avatar_url,
+                ss.points
+            FROM
+                season_standings ss
+            INNER JOIN
+                brawlers b ON b.id = ss.brawler_id
+            WHERE
+                ss.season_id = $1
+            ORDER BY
+                ss.rank
+            LIMIT $2
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(season_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<SeasonStandingModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn archive(&self, season_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let archived = conn.transaction::<usize, anyhow::Error, _>(|conn| {
+            let snapshot = r#"
+                INSERT INTO season_standings (season_id, brawler_id, "rank", points)
+                SELECT
+                    season_id,
+                    brawler_id,
+                    ROW_NUMBER() OVER (ORDER BY points DESC, updated_at ASC, brawler_id ASC),
+                    points
+                FROM
+                    season_points
+                WHERE
+                    season_id = $1
+                ON CONFLICT (season_id, brawler_id) DO NOTHING
+            "#;
+
+            let rows = diesel::sql_query(snapshot)
+                .bind::<diesel::sql_types::Int4, _>(season_id)
+                .execute(conn)?;
+
+            update(seasons::table)
+                .filter(seasons::id.eq(season_id))
+                .filter(seasons::archived_at.is_null())
+                .set(seasons::archived_at.eq(now))
+                .execute(conn)?;
+
+            Ok(rows)
+        })?;
+
+        Ok(archived)
+    }
+}
@@ -1,5 +1,74 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "invitation_status"))]
+    pub struct InvitationStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "join_request_status"))]
+    pub struct JoinRequestStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "lane"))]
+    pub struct Lane;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "mission_status"))]
+    pub struct MissionStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "rank_tier"))]
+    pub struct RankTier;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "recurrence_frequency"))]
+    pub struct RecurrenceFrequency;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    achievements (id) {
+        id -> Int4,
+        #[max_length = 50]
+        code -> Varchar,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Text,
+        #[max_length = 255]
+        icon -> Varchar,
+        #[max_length = 50]
+        criteria -> Varchar,
+        threshold -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_achievements (brawler_id, achievement_id) {
+        brawler_id -> Int4,
+        achievement_id -> Int4,
+        unlocked_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_level_ups (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 20]
+        from_tier -> Varchar,
+        #[max_length = 20]
+        to_tier -> Varchar,
+        total_points -> Int4,
+        seen_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -16,25 +85,196 @@ diesel::table! {
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
         total_points -> Int4,
+        current_streak -> Int4,
+        longest_streak -> Int4,
+        last_active_date -> Nullable<Date>,
+        spendable_points -> Int4,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Lane;
+
     crew_memberships (mission_id, brawler_id) {
         mission_id -> Int4,
         brawler_id -> Int4,
         joined_at -> Timestamp,
+        from_transfer -> Bool,
+        lane -> Nullable<Lane>,
     }
 }
 
 diesel::table! {
-    missions (id) {
+    use diesel::sql_types::*;
+    use super::sql_types::JoinRequestStatus;
+
+    join_requests (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        status -> JoinRequestStatus,
+        decided_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_bans (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        banned_by -> Int4,
+        reason -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::InvitationStatus;
+
+    mission_invitations (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        invited_by -> Int4,
+        status -> InvitationStatus,
+        expires_at -> Timestamp,
+        decided_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Lane;
+
+    mission_lanes (mission_id, lane) {
+        mission_id -> Int4,
+        lane -> Lane,
+        slots -> Int4,
+    }
+}
+
+diesel::table! {
+    mission_mvp_votes (mission_id, voter_id) {
+        mission_id -> Int4,
+        voter_id -> Int4,
+        nominee_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_mvps (mission_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        votes -> Int4,
+        bonus_points -> Int4,
+        awarded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int4,
+        tags -> Array<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RecurrenceFrequency;
+
+    mission_series (id) {
+        id -> Int4,
+        chief_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        max_crew -> Int4,
+        #[max_length = 20]
+        difficulty -> Varchar,
+        due_time -> Nullable<Time>,
+        frequency -> RecurrenceFrequency,
+        weekdays -> Array<Text>,
+        start_date -> Date,
+        end_date -> Nullable<Date>,
+        max_occurrences -> Nullable<Int4>,
+        occurrences_created -> Int4,
+        last_occurrence_on -> Nullable<Date>,
+        next_occurrence_on -> Nullable<Date>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MissionStatus;
+
+    mission_status_history (id) {
         id -> Int4,
+        mission_id -> Int4,
+        from_status -> Nullable<MissionStatus>,
+        to_status -> MissionStatus,
+        actor_id -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_tags (mission_id, tag_id) {
+        mission_id -> Int4,
+        tag_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_templates (id) {
+        id -> Int4,
+        owner_id -> Nullable<Int4>,
         #[max_length = 255]
         name -> Varchar,
         description -> Nullable<Text>,
+        #[max_length = 20]
+        difficulty -> Varchar,
+        max_crew -> Int4,
+        due_in_hours -> Nullable<Int4>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_waitlist (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MissionStatus;
+    use super::sql_types::Tsvector;
+    use super::sql_types::RankTier;
+
+    missions (id) {
+        id -> Int4,
         #[max_length = 255]
-        status -> Varchar,
+        name -> Varchar,
+        description -> Nullable<Text>,
+        status -> MissionStatus,
         chief_id -> Int4,
         created_at -> Timestamp,
         updated_at -> Timestamp,
@@ -44,11 +284,171 @@ diesel::table! {
         difficulty -> Varchar,
         base_points -> Int4,
         due_date -> Nullable<Timestamp>,
+        archived_at -> Nullable<Timestamp>,
+        due_reminded_at -> Nullable<Timestamp>,
+        series_id -> Nullable<Int4>,
+        search_vector -> Tsvector,
+        requires_approval -> Bool,
+        min_rank -> Nullable<RankTier>,
+        max_rank -> Nullable<RankTier>,
+        min_total_points -> Nullable<Int4>,
+        finished_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    notifications (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        mission_id -> Nullable<Int4>,
+        #[max_length = 50]
+        kind -> Varchar,
+        message -> Text,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    point_ledger (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        points -> Int4,
+        created_at -> Timestamp,
+        #[max_length = 50]
+        reason -> Varchar,
+        mission_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    reward_redemptions (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        reward_id -> Int4,
+        cost -> Int4,
+        redeemed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    rewards (id) {
+        id -> Int4,
+        #[max_length = 50]
+        code -> Varchar,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 20]
+        kind -> Varchar,
+        #[max_length = 255]
+        value -> Varchar,
+        cost -> Int4,
+        stock -> Nullable<Int4>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    season_points (season_id, brawler_id) {
+        season_id -> Int4,
+        brawler_id -> Int4,
+        points -> Int4,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    season_standings (season_id, brawler_id) {
+        season_id -> Int4,
+        brawler_id -> Int4,
+        rank -> Int4,
+        points -> Int4,
+        archived_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    seasons (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        archived_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 30]
+        name -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
+diesel::joinable!(brawler_achievements -> achievements (achievement_id));
+diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
+diesel::joinable!(brawler_level_ups -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(join_requests -> brawlers (brawler_id));
+diesel::joinable!(join_requests -> missions (mission_id));
+diesel::joinable!(mission_bans -> missions (mission_id));
+diesel::joinable!(mission_invitations -> missions (mission_id));
+diesel::joinable!(mission_lanes -> missions (mission_id));
+diesel::joinable!(mission_mvp_votes -> missions (mission_id));
+diesel::joinable!(mission_mvps -> brawlers (brawler_id));
+diesel::joinable!(mission_mvps -> missions (mission_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_series -> brawlers (chief_id));
+diesel::joinable!(mission_status_history -> brawlers (actor_id));
+diesel::joinable!(mission_status_history -> missions (mission_id));
+diesel::joinable!(mission_tags -> missions (mission_id));
+diesel::joinable!(mission_tags -> tags (tag_id));
+diesel::joinable!(mission_templates -> brawlers (owner_id));
+diesel::joinable!(mission_waitlist -> brawlers (brawler_id));
+diesel::joinable!(mission_waitlist -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> mission_series (series_id));
+diesel::joinable!(notifications -> brawlers (brawler_id));
+diesel::joinable!(notifications -> missions (mission_id));
+diesel::joinable!(point_ledger -> brawlers (brawler_id));
+diesel::joinable!(point_ledger -> missions (mission_id));
+diesel::joinable!(reward_redemptions -> brawlers (brawler_id));
+diesel::joinable!(reward_redemptions -> rewards (reward_id));
+diesel::joinable!(season_points -> brawlers (brawler_id));
+diesel::joinable!(season_points -> seasons (season_id));
+diesel::joinable!(season_standings -> brawlers (brawler_id));
+diesel::joinable!(season_standings -> seasons (season_id));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    achievements,
+    brawler_achievements,
+    brawler_level_ups,
+    brawlers,
+    crew_memberships,
+    join_requests,
+    mission_bans,
+    mission_invitations,
+    mission_lanes,
+    mission_mvp_votes,
+    mission_mvps,
+    mission_ratings,
+    mission_series,
+    mission_status_history,
+    mission_tags,
+    mission_templates,
+    mission_waitlist,
+    missions,
+    notifications,
+    point_ledger,
+    reward_redemptions,
+    rewards,
+    season_points,
+    season_standings,
+    seasons,
+    tags,
+);
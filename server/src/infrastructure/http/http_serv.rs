@@ -19,8 +19,10 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig,
-    infrastructure::{database::postgresql_connection::PgPoolSquad, http::routers},
+    config::{
+        config_model::DotEnvyConfig,
+    },
+    infrastructure::{database::postgresql_connection::PgPoolSquad, http::routers, scheduler},
 };
 
 fn static_serve() -> Router {
@@ -31,43 +33,81 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_serve(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Router {
     Router::new()
         .nest(
             "/brawlers", 
-            routers::brawlers::routes(Arc::clone(&db_pool)))
+            routers::brawlers::routes(Arc::clone(&db_pool), Arc::clone(&config)))
         .nest(
             "/authentication", 
             routers::authentication::routes(Arc::clone(&db_pool)))
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool)),
+            routers::mission_management::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/crew",
-            routers::crew_operation::routes(Arc::clone(&db_pool)),
+            routers::crew_operation::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/mission",
-            routers::mission_operation::routes(Arc::clone(&db_pool)),
+            routers::mission_operation::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/view",
-            routers::mission_viewing::routes(Arc::clone(&db_pool)),
+            routers::mission_viewing::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/chat",
             routers::chat::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/achievements",
+            routers::achievements::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/seasons",
+            routers::seasons::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/ratings",
+            routers::ratings::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/rewards",
+            routers::rewards::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/mission-templates",
+            routers::mission_templates::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/mission-series",
+            routers::mission_series::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/mission-invitations",
+            routers::mission_invitations::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/notifications",
+            routers::notifications::routes(Arc::clone(&db_pool)),
+        )
 
         .route("/error/{status_code_u16}", get(routers::default::error))
         .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    scheduler::spawn_season_rollover(Arc::clone(&db_pool), Arc::clone(&config));
+    scheduler::spawn_mvp_settlement(Arc::clone(&db_pool), Arc::clone(&config));
+    scheduler::spawn_due_date_enforcement(Arc::clone(&db_pool), Arc::clone(&config));
+    scheduler::spawn_mission_series(Arc::clone(&db_pool), Arc::clone(&config));
+    scheduler::spawn_mission_archival(Arc::clone(&db_pool), Arc::clone(&config));
+
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api/v1", api_serve(Arc::clone(&db_pool)))
+        .nest("/api/v1", api_serve(Arc::clone(&config), Arc::clone(&db_pool)))
         // .fallback(default_router::health_check)
         // .route("/health_check", get(routers::default::health_check))
         .layer(TimeoutLayer::new(Duration::from_secs(
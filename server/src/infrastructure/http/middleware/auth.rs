@@ -7,13 +7,7 @@ use axum::{
 
 use crate::config::config_loader::get_jwt_env;
 
-pub async fn authorization(mut req: Request, next: Next) -> Result<Response, StatusCode> {
-    let auth_header = req
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|value| value.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
-
+fn verify_bearer(auth_header: &str) -> Result<i32, StatusCode> {
     let token = auth_header
         .strip_prefix("Bearer ")
         .ok_or(StatusCode::UNAUTHORIZED)?;
@@ -23,10 +17,35 @@ pub async fn authorization(mut req: Request, next: Next) -> Result<Response, Sta
     let claims = crate::infrastructure::jwt::verify_token(secret_env.secret, token.to_string())
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    let brawler_id = claims
-        .sub;
+    Ok(claims.sub)
+}
+
+pub async fn authorization(mut req: Request, next: Next) -> Result<Response, StatusCode> {
+    let auth_header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let brawler_id = verify_bearer(auth_header)?;
 
     req.extensions_mut().insert(brawler_id);
 
     Ok(next.run(req).await)
 }
+
+/// For public routes that tailor results to the caller when a token is sent.
+/// A missing header passes through; a bad token is still rejected.
+pub async fn optional_authorization(mut req: Request, next: Next) -> Result<Response, StatusCode> {
+    let auth_header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    if let Some(auth_header) = auth_header {
+        let brawler_id = verify_bearer(auth_header)?;
+        req.extensions_mut().insert(brawler_id);
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::achievements::AchievementUseCase,
+    domain::repositories::achievements::AchievementRepository,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::achievements::AchievementPostgres,
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let achievement_repository = AchievementPostgres::new(db_pool);
+    let use_case = AchievementUseCase::new(Arc::new(achievement_repository));
+
+    Router::new()
+        .route(
+            "/me",
+            get(my_achievements).layer(middleware::from_fn(authorization)),
+        )
+        .route("/", get(catalog))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn catalog<T>(
+    State(achievement_use_case): State<Arc<AchievementUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: AchievementRepository + Send + Sync,
+{
+    match achievement_use_case.get_catalog().await {
+        Ok(catalog) => (StatusCode::OK, Json(catalog)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn my_achievements<T>(
+    State(achievement_use_case): State<Arc<AchievementUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: AchievementRepository + Send + Sync,
+{
+    match achievement_use_case.get_unlocked(brawler_id).await {
+        Ok(badges) => (StatusCode::OK, Json(badges)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -2,29 +2,51 @@ use std::sync::Arc;
 
 use axum::{
     Json, Router,
-    extract::{Extension, State},
+    extract::{Extension, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{get, patch, post},
 };
+use serde::Deserialize;
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
-    domain::value_objects::{brawler_model::RegisterBrawlerModel, uploaded_image::UploadedAvartar},
+    config::config_model::DotEnvyConfig,
+    domain::value_objects::{
+        brawler_model::RegisterBrawlerModel, leaderboard_model::LeaderboardFilter,
+        uploaded_image::UploadedAvartar,
+    },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementPostgres, brawlers::BrawlerPostgres,
+                rewards::RewardPostgres,
+            },
+        },
         http::middleware::auth::authorization,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let brawlers_repository = BrawlerPostgres::new(db_pool);
-    let brawlers_use_case = BrawlersUseCase::new(Arc::new(brawlers_repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let brawlers_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let reward_repository = RewardPostgres::new(Arc::clone(&db_pool));
+    let brawlers_use_case = BrawlersUseCase::new(
+        Arc::new(brawlers_repository),
+        Arc::new(achievement_repository),
+        Arc::new(reward_repository),
+        config,
+    );
 
     let protected_router = Router::new()
         .route("/avatar", post(upload_avatar))
         .route("/me", get(get_me))
         .route("/my-missions", get(get_missions))
+        .route("/level-ups", get(get_level_ups))
+        .route("/level-ups/seen", patch(mark_level_ups_seen))
+        .route("/leaderboard/me", get(get_my_rank))
+        .route("/point-history", get(get_point_history))
         .route_layer(axum::middleware::from_fn(authorization));
 
     Router::new()
@@ -35,16 +57,28 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
 }
 
 pub async fn get_leaderboard(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres>>>,
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
+    Query(filter): Query<LeaderboardFilter>,
 ) -> impl IntoResponse {
-    match brawlers_use_case.get_leaderboard().await {
+    match brawlers_use_case.get_leaderboard(&filter).await {
         Ok(leaderboard) => (StatusCode::OK, Json(leaderboard)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
+pub async fn get_my_rank(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Query(filter): Query<LeaderboardFilter>,
+) -> impl IntoResponse {
+    match brawlers_use_case.get_my_rank(brawler_id, &filter).await {
+        Ok(my_rank) => (StatusCode::OK, Json(my_rank)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub async fn register(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres>>>,
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
     Json(register_brawler_model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse {
     match brawlers_use_case.register(register_brawler_model).await {
@@ -54,7 +88,7 @@ pub async fn register(
 }
 
 pub async fn upload_avatar(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres>>>,
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
     Extension(brawler_id): Extension<i32>,
     Json(upload_image): Json<UploadedAvartar>,
 ) -> impl IntoResponse {
@@ -68,14 +102,14 @@ pub async fn upload_avatar(
 }
 
 pub async fn get_missions(
-    State(_brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres>>>,
+    State(_brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
     Extension(_brawler_id): Extension<i32>,
 ) -> impl IntoResponse {
     (StatusCode::OK, Json(serde_json::json!({})))
 }
 
 pub async fn get_me(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres>>>,
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
     Extension(brawler_id): Extension<i32>,
 ) -> impl IntoResponse {
     match brawlers_use_case.get_me(brawler_id).await {
@@ -83,3 +117,58 @@ pub async fn get_me(
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct LevelUpQuery {
+    pub unseen_only: Option<bool>,
+}
+
+pub async fn get_level_ups(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<LevelUpQuery>,
+) -> impl IntoResponse {
+    match brawlers_use_case
+        .get_level_ups(brawler_id, query.unseen_only.unwrap_or(false))
+        .await
+    {
+        Ok(level_ups) => (StatusCode::OK, Json(level_ups)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PointHistoryQuery {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset_token: Option<String>,
+}
+
+pub async fn get_point_history(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<PointHistoryQuery>,
+) -> impl IntoResponse {
+    match brawlers_use_case
+        .get_point_history(
+            brawler_id,
+            query.page,
+            query.limit,
+            query.offset_token.as_deref(),
+        )
+        .await
+    {
+        Ok(history) => (StatusCode::OK, Json(history)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_level_ups_seen(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<BrawlerPostgres, AchievementPostgres, RewardPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse {
+    match brawlers_use_case.mark_level_ups_seen(brawler_id).await {
+        Ok(count) => (StatusCode::OK, Json(serde_json::json!({ "seen": count }))).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
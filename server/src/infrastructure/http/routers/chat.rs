@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, Json, Extension},
+    extract::{Path, Json, Extension, Query},
     routing::get,
     Router,
     http::StatusCode,
@@ -12,7 +12,10 @@ use crate::{
         database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
         http::middleware::auth::authorization,
     },
-    domain::repositories::brawlers::BrawlerRepository,
+    domain::{
+        repositories::brawlers::BrawlerRepository,
+        value_objects::page_model::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, Page, PageRequest},
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +24,13 @@ pub struct ChatResponse {
     pub message: String,
 }
 
+#[derive(Deserialize)]
+pub struct MessagesQuery {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset_token: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct CreateMessageRequest {
     pub content: String,
@@ -36,9 +46,13 @@ pub fn routes(pool: Arc<PgPoolSquad>) -> Router {
 pub async fn get_messages(
     Path(mission_id): Path<i32>,
     Extension(_brawler_id): Extension<i32>,
-) -> Result<Json<Vec<ChatMessage>>, (StatusCode, String)> {
+    Query(query): Query<MessagesQuery>,
+) -> Result<Json<Page<ChatMessage>>, (StatusCode, String)> {
+    let page_request = PageRequest::resolve(query.page, query.limit, query.offset_token.as_deref(), DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     let use_case = ChatUseCase::new();
-    let messages = use_case.get_messages(mission_id).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let messages = use_case.get_messages(mission_id, page_request).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
     Ok(Json(messages))
 }
 
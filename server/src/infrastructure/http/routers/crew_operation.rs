@@ -1,57 +1,109 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
-    extract::{Path, State},
+    Extension, Json, Router,
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
+    routing::{delete, get, patch, post},
 };
+use serde::Deserialize;
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
-    domain::repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::{
+            crew_model::{KickCrewModel, TransferChiefModel},
+            join_request_statuses::{JoinOutcomes, JoinRequestStatuses},
+            lanes::Lanes,
+        },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
+                achievements::AchievementPostgres, crew_operation::CrewOperationPostgres,
+                mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres,
             },
         },
         http::middleware::auth::authorization,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
     let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let notification_repository = NotificationPostgres::new(Arc::clone(&db_pool));
 
     let use_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
         Arc::new(mission_viewing_repository),
+        Arc::new(achievement_repository),
+        Arc::new(notification_repository),
+        config,
     );
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
+        .route("/lane/{mission_id}", patch(claim_lane))
+        .route("/kick/{mission_id}", post(kick))
+        .route("/transfer/{mission_id}", patch(transfer_chief))
+        .route("/waitlist/{mission_id}", get(waitlist).delete(leave_waitlist))
+        .route("/requests/mine", get(my_join_requests))
+        .route("/requests/mission/{mission_id}", get(mission_join_requests))
+        .route("/requests/{request_id}", delete(withdraw_join_request))
+        .route("/requests/{request_id}/accept", patch(accept_join_request))
+        .route("/requests/{request_id}/reject", patch(reject_join_request))
         .route_layer(middleware::from_fn(authorization))
         .with_state(Arc::new(use_case))
 }
 
-pub async fn join<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+#[derive(Debug, Deserialize)]
+pub struct LaneQuery {
+    pub lane: Option<Lanes>,
+}
+
+pub async fn join<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Query(query): Query<LaneQuery>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
 {
-    match crew_operation_use_case.join(mission_id, brawler_id).await {
-        Ok(_) => (
+    match crew_operation_use_case
+        .join(mission_id, brawler_id, query.lane)
+        .await
+    {
+        Ok(JoinOutcomes::Requested { request_id }) => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Brawler id: {}, has requested to join mission id: {} (request id: {})",
+                brawler_id, mission_id, request_id
+            ),
+        )
+            .into_response(),
+        Ok(JoinOutcomes::Waitlisted { position }) => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Mission id: {} is full, brawler id: {} is number {} on its waitlist",
+                mission_id, brawler_id, position
+            ),
+        )
+            .into_response(),
+        Ok(JoinOutcomes::Joined) => (
             StatusCode::OK,
             format!(
                 "Brawler id: {}, has joined mission id: {}",
@@ -63,14 +115,16 @@ where
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn leave<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
 {
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
         Ok(_) => (
@@ -84,3 +138,256 @@ where
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn claim_lane<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<LaneQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .claim_lane(mission_id, brawler_id, query.lane)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            match query.lane {
+                Some(lane) => format!(
+                    "Brawler id: {}, has claimed the {} lane in mission id: {}",
+                    brawler_id, lane, mission_id
+                ),
+                None => format!(
+                    "Brawler id: {}, has freed their lane in mission id: {}",
+                    brawler_id, mission_id
+                ),
+            },
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn kick<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(kick_crew_model): Json<KickCrewModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    let kicked_id = kick_crew_model.brawler_id;
+    match crew_operation_use_case
+        .kick(mission_id, brawler_id, kick_crew_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, has been removed from mission id: {}",
+                kicked_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn transfer_chief<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(transfer_chief_model): Json<TransferChiefModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    let new_chief_id = transfer_chief_model.brawler_id;
+    match crew_operation_use_case
+        .transfer_chief(mission_id, brawler_id, transfer_chief_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, is now the chief of mission id: {}",
+                new_chief_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn waitlist<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case.get_waitlist(mission_id).await {
+        Ok(entries) => (StatusCode::OK, Json(entries)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn leave_waitlist<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .leave_waitlist(mission_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, has left the waitlist of mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JoinRequestListQuery {
+    pub status: Option<JoinRequestStatuses>,
+}
+
+pub async fn my_join_requests<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<JoinRequestListQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .get_my_join_requests(brawler_id, query.status)
+        .await
+    {
+        Ok(requests) => (StatusCode::OK, Json(requests)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mission_join_requests<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<JoinRequestListQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .get_mission_join_requests(mission_id, brawler_id, query.status)
+        .await
+    {
+        Ok(requests) => (StatusCode::OK, Json(requests)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept_join_request<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(request_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .accept_join_request(request_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request id: {}, has been accepted", request_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn reject_join_request<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(request_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .reject_join_request(request_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request id: {}, has been rejected", request_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn withdraw_join_request<T1, T2, T3, T4>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(request_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: NotificationRepository + Send + Sync,
+{
+    match crew_operation_use_case
+        .withdraw_join_request(request_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request id: {}, has been withdrawn", request_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
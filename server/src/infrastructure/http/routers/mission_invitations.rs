@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::use_cases::mission_invitations::MissionInvitationUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::{
+            achievements::AchievementRepository, brawlers::BrawlerRepository,
+            crew_operation::CrewOperationRepository,
+            mission_invitations::MissionInvitationRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::mission_invitation_model::{InvitationStatuses, InviteBrawlerModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementPostgres, brawlers::BrawlerPostgres,
+                crew_operation::CrewOperationPostgres,
+                mission_invitations::MissionInvitationPostgres,
+                mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+type MissionInvitationState<T1, T2, T3, T4, T5, T6> =
+    State<Arc<MissionInvitationUseCase<T1, T2, T3, T4, T5, T6>>>;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let mission_invitation_repository = MissionInvitationPostgres::new(Arc::clone(&db_pool));
+    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
+    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let notification_repository = NotificationPostgres::new(Arc::clone(&db_pool));
+    let use_case = MissionInvitationUseCase::new(
+        Arc::new(mission_invitation_repository),
+        Arc::new(crew_operation_repository),
+        Arc::new(mission_viewing_repository),
+        Arc::new(brawler_repository),
+        Arc::new(achievement_repository),
+        Arc::new(notification_repository),
+        config,
+    );
+
+    Router::new()
+        .route("/mine", get(mine))
+        .route("/mission/{mission_id}", get(for_mission).post(invite))
+        .route("/{invitation_id}/accept", patch(accept))
+        .route("/{invitation_id}/decline", patch(decline))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InvitationListQuery {
+    pub status: Option<InvitationStatuses>,
+}
+
+pub async fn invite<T1, T2, T3, T4, T5, T6>(
+    State(mission_invitation_use_case): MissionInvitationState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(invite_brawler_model): Json<InviteBrawlerModel>,
+) -> impl IntoResponse
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_invitation_use_case
+        .invite(mission_id, brawler_id, invite_brawler_model)
+        .await
+    {
+        Ok(invitation_id) => {
+            let json_value = serde_json::json!({
+                "invitation_id": invitation_id,
+            });
+            (StatusCode::CREATED, Json(json_value)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mine<T1, T2, T3, T4, T5, T6>(
+    State(mission_invitation_use_case): MissionInvitationState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<InvitationListQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_invitation_use_case
+        .get_mine(brawler_id, query.status)
+        .await
+    {
+        Ok(invitations) => (StatusCode::OK, Json(invitations)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn for_mission<T1, T2, T3, T4, T5, T6>(
+    State(mission_invitation_use_case): MissionInvitationState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<InvitationListQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_invitation_use_case
+        .get_for_mission(mission_id, brawler_id, query.status)
+        .await
+    {
+        Ok(invitations) => (StatusCode::OK, Json(invitations)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept<T1, T2, T3, T4, T5, T6>(
+    State(mission_invitation_use_case): MissionInvitationState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_invitation_use_case
+        .accept(invitation_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Invitation id: {}, has been accepted", invitation_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn decline<T1, T2, T3, T4, T5, T6>(
+    State(mission_invitation_use_case): MissionInvitationState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionInvitationRepository + Send + Sync,
+    T2: CrewOperationRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_invitation_use_case
+        .decline(invitation_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Invitation id: {}, has been declined", invitation_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -4,25 +4,31 @@ use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, mi
 
 use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
+    config::config_model::DotEnvyConfig,
     domain::{
         repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
             mission_management::MissionManagementRepository,
-            mission_viewing::MissionViewingRepository,
-            brawlers::BrawlerRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
         },
         value_objects::mission_model::{AddMissionModel, EditMissionModel},
-    }, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres, brawlers::BrawlerPostgres}}, http::middleware::auth::authorization},
+    }, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{achievements::AchievementPostgres, crew_operation::CrewOperationPostgres, mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres}}, http::middleware::auth::authorization},
 };
 
-pub async fn add<T1, T2, T3>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+type MissionManagementState<T1, T2, T3, T4, T5> =
+    State<Arc<MissionManagementUseCase<T1, T2, T3, T4, T5>>>;
+
+pub async fn add<T1, T2, T3, T4, T5>(
+    State(mission_management_use_case): MissionManagementState<T1, T2, T3, T4, T5>,
     Extension(brawler_id): Extension<i32>,
     Json(add_mission_model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
-    T3: BrawlerRepository + Send + Sync,
+    T3: CrewOperationRepository + Send + Sync + 'static,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_management_use_case
         .add(brawler_id, add_mission_model)
@@ -39,8 +45,8 @@ where
 }
 
 
-pub async fn edit<T1, T2, T3>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+pub async fn edit<T1, T2, T3, T4, T5>(
+    State(mission_management_use_case): MissionManagementState<T1, T2, T3, T4, T5>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
     Json(edit_mission_model): Json<EditMissionModel>,
@@ -48,7 +54,9 @@ pub async fn edit<T1, T2, T3>(
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
-    T3: BrawlerRepository + Send + Sync,
+    T3: CrewOperationRepository + Send + Sync + 'static,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_management_use_case
         .edit(mission_id, brawler_id, edit_mission_model)
@@ -62,15 +70,17 @@ where
     }
 }
 
-pub async fn remove<T1, T2, T3>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+pub async fn remove<T1, T2, T3, T4, T5>(
+    State(mission_management_use_case): MissionManagementState<T1, T2, T3, T4, T5>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
-    T3: BrawlerRepository + Send + Sync,
+    T3: CrewOperationRepository + Send + Sync + 'static,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_management_use_case
         .remove(mission_id, brawler_id)
@@ -85,15 +95,17 @@ where
 }
 
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
     let mission_management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
 
-    let mission_management_use_case: MissionManagementUseCase<MissionManagementPostgres, MissionViewingPostgres, BrawlerPostgres> = MissionManagementUseCase::new(
+    let mission_management_use_case = MissionManagementUseCase::new(
         Arc::new(mission_management_repository),
         Arc::new(mission_viewing_repository),
-        Arc::new(brawler_repository),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+        config,
     );
 
     Router::new()
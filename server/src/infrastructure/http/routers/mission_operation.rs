@@ -11,44 +11,57 @@ use axum::{
 
 use crate::{
     application::use_cases::mission_operation::MissionOperationUseCase,
+    config::config_model::DotEnvyConfig,
     domain::{
         repositories::{
+            achievements::AchievementRepository,
             mission_operation::MissionOperationRepository,
             mission_viewing::MissionViewingRepository,
             brawlers::BrawlerRepository,
+            notifications::NotificationRepository,
         },
         value_objects::mission_statuses::MissionStatuses,
     },
     infrastructure::{database::{
         postgresql_connection::PgPoolSquad,
         repositories::{
+            achievements::AchievementPostgres,
             mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres,
-            brawlers::BrawlerPostgres,
+            brawlers::BrawlerPostgres, notifications::NotificationPostgres,
         },
     }, http::middleware::auth::authorization},
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+type MissionOperationState<T1, T2, T3, T4, T5> =
+    State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
     let mission_operation_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let notification_repository = NotificationPostgres::new(Arc::clone(&db_pool));
 
     let use_case = MissionOperationUseCase::new(
         Arc::new(mission_operation_repository),
         Arc::new(mission_viewing_repository),
         Arc::new(brawler_repository),
+        Arc::new(achievement_repository),
+        Arc::new(notification_repository),
+        config,
     );
 
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
+        .route("/to-cancelled/{mission_id}", patch(to_cancelled))
         .route_layer(middleware::from_fn(authorization))
         .with_state(Arc::new(use_case))
 }
 
-pub async fn in_progress<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+pub async fn in_progress<T1, T2, T3, T4, T5>(
+    State(mission_operation_use_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
@@ -56,6 +69,8 @@ where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_operation_use_case.in_progress(mission_id, chief_id).await {
         Ok(mission_id) => {
@@ -70,8 +85,8 @@ where
     }
 }
 
-pub async fn to_completed<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+pub async fn to_completed<T1, T2, T3, T4, T5>(
+    State(mission_operation_use_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
@@ -79,6 +94,8 @@ where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_operation_use_case
         .to_completed(mission_id, chief_id)
@@ -97,8 +114,8 @@ where
     }
 }
 
-pub async fn to_failed<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+pub async fn to_failed<T1, T2, T3, T4, T5>(
+    State(mission_operation_use_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
@@ -106,6 +123,8 @@ where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
 {
     match mission_operation_use_case
         .to_failed(mission_id, chief_id)
@@ -123,3 +142,32 @@ where
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn to_cancelled<T1, T2, T3, T4, T5>(
+    State(mission_operation_use_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: NotificationRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .cancel(mission_id, chief_id)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!(
+                "Mission ({}) is now {:?}",
+                mission_id,
+                MissionStatuses::Cancelled
+            ),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
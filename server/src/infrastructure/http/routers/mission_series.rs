@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::mission_series::MissionSeriesRepository,
+        value_objects::mission_series_model::{AddMissionSeriesModel, EditMissionSeriesModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_series::MissionSeriesPostgres,
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let mission_series_repository = MissionSeriesPostgres::new(db_pool);
+    let use_case =
+        MissionSeriesUseCase::new(Arc::new(mission_series_repository), config);
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(my_series))
+        .route("/{series_id}", patch(edit))
+        .route("/{series_id}", delete(stop))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn add<T>(
+    State(mission_series_use_case): State<Arc<MissionSeriesUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(add_model): Json<AddMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    match mission_series_use_case.add(brawler_id, add_model).await {
+        Ok(series_id) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({ "series_id": series_id })),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn my_series<T>(
+    State(mission_series_use_case): State<Arc<MissionSeriesUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    match mission_series_use_case.get_mine(brawler_id).await {
+        Ok(series) => (StatusCode::OK, Json(series)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T>(
+    State(mission_series_use_case): State<Arc<MissionSeriesUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+    Json(edit_model): Json<EditMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    match mission_series_use_case
+        .edit(series_id, brawler_id, edit_model)
+        .await
+    {
+        Ok(series_id) => (
+            StatusCode::OK,
+            format!("Edit mission series success with id: {}", series_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn stop<T>(
+    State(mission_series_use_case): State<Arc<MissionSeriesUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionSeriesRepository + Send + Sync,
+{
+    match mission_series_use_case.stop(series_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Stop mission series success with id: {}", series_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
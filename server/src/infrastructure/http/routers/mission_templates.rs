@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::use_cases::mission_templates::MissionTemplateUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::{
+            achievements::AchievementRepository, crew_operation::CrewOperationRepository,
+            mission_management::MissionManagementRepository,
+            mission_templates::MissionTemplateRepository,
+            mission_viewing::MissionViewingRepository, notifications::NotificationRepository,
+        },
+        value_objects::mission_template_model::{
+            AddMissionTemplateModel, EditMissionTemplateModel, MissionFromTemplateModel,
+            TemplateScopes,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementPostgres, crew_operation::CrewOperationPostgres,
+                mission_management::MissionManagementPostgres,
+                mission_templates::MissionTemplatePostgres,
+                mission_viewing::MissionViewingPostgres, notifications::NotificationPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+type MissionTemplateState<T1, T2, T3, T4, T5, T6> =
+    State<Arc<MissionTemplateUseCase<T1, T2, T3, T4, T5, T6>>>;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let mission_template_repository = MissionTemplatePostgres::new(Arc::clone(&db_pool));
+    let mission_management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let use_case = MissionTemplateUseCase::new(
+        Arc::new(mission_template_repository),
+        Arc::new(mission_management_repository),
+        Arc::new(mission_viewing_repository),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(Arc::clone(&db_pool))),
+        config,
+    );
+
+    Router::new()
+        .route("/", get(list).post(add))
+        .route("/{template_id}", get(view).patch(edit).delete(remove))
+        .route("/{template_id}/missions", post(create_mission))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateListQuery {
+    pub scope: Option<TemplateScopes>,
+}
+
+pub async fn list<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<TemplateListQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case
+        .get_visible(brawler_id, query.scope)
+        .await
+    {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn view<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case
+        .get_one(template_id, brawler_id)
+        .await
+    {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Json(add_model): Json<AddMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case.add(brawler_id, add_model).await {
+        Ok(template_id) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({ "template_id": template_id })),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(edit_model): Json<EditMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case
+        .edit(template_id, brawler_id, edit_model)
+        .await
+    {
+        Ok(template_id) => (
+            StatusCode::OK,
+            format!("Edit mission template success with id: {}", template_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case
+        .remove(template_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove mission template success with id: {}", template_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn create_mission<T1, T2, T3, T4, T5, T6>(
+    State(mission_template_use_case): MissionTemplateState<T1, T2, T3, T4, T5, T6>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(overrides): Json<MissionFromTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionViewingRepository + Send + Sync,
+    T4: CrewOperationRepository + Send + Sync + 'static,
+    T5: AchievementRepository + Send + Sync,
+    T6: NotificationRepository + Send + Sync,
+{
+    match mission_template_use_case
+        .create_mission(template_id, brawler_id, overrides)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({ "mission_id": mission_id })),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
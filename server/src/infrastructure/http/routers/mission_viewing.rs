@@ -10,8 +10,11 @@ use axum::{
     Json, Router,
 };
 
+use serde::Deserialize;
+
 use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
+    config::config_model::DotEnvyConfig,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::mission_filter::MissionFilter,
@@ -20,13 +23,13 @@ use crate::{
         database::{
             postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
         },
-        http::middleware::auth::authorization, // 👈 [ใหม่] Import middleware เช็คสิทธิ์
+        http::middleware::auth::{authorization, optional_authorization}, // 👈 [ใหม่] Import middleware เช็คสิทธิ์
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
     let mission_viewing_repository = MissionViewingPostgres::new(db_pool);
-    let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository));
+    let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository), config);
 
     Router::new()
         // 👇 [ใหม่] เพิ่ม Route นี้ครับ (ต้องอยู่ก่อน /{mission_id} เพื่อความชัวร์)
@@ -36,15 +39,28 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         )
         // -----------------------------------------------------------
         .route("/{mission_id}", get(view_details))
-        .route("/gets", get(gets))
+        .route(
+            "/gets",
+            get(gets).layer(middleware::from_fn(optional_authorization)),
+        )
         .route("/count/{mission_id}", get(get_mission_count))
+        .route("/history/{mission_id}", get(get_status_history))
+        .route("/tags/popular", get(get_popular_tags))
         .with_state(Arc::new(use_case))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MyMissionsQuery {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset_token: Option<String>,
+}
+
 // 👇 [ใหม่] เพิ่มฟังก์ชันนี้สำหรับดึงภารกิจของฉัน
 pub async fn my_missions<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Extension(brawler_id): Extension<i32>, // ดึง ID จาก Token
+    Query(query): Query<MyMissionsQuery>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
@@ -54,6 +70,10 @@ where
         name: None,
         status: None,
         chief_id: Some(brawler_id), // ⚠️ ต้องแก้ Struct MissionFilter ให้มี field นี้ก่อนนะ
+        page: query.page,
+        limit: query.limit,
+        offset_token: query.offset_token,
+        ..Default::default()
     };
 
     match mission_viewing_use_case.get_all(&filter).await {
@@ -77,11 +97,14 @@ where
 
 pub async fn gets<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
-    filter: Query<MissionFilter>,
+    viewer: Option<Extension<i32>>,
+    Query(mut filter): Query<MissionFilter>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
+    filter.viewer_id = viewer.map(|Extension(brawler_id)| brawler_id);
+
     match mission_viewing_use_case.get_all(&filter).await {
         Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
@@ -99,4 +122,35 @@ where
         Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
-}
\ No newline at end of file
+}
+
+pub async fn get_status_history<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.get_status_history(mission_id).await {
+        Ok(history) => (StatusCode::OK, Json(history)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PopularTagsQuery {
+    pub limit: Option<i64>,
+}
+
+pub async fn get_popular_tags<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Query(query): Query<PopularTagsQuery>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.get_popular_tags(query.limit).await {
+        Ok(tags) => (StatusCode::OK, Json(tags)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -1,3 +1,4 @@
+pub mod achievements;
 pub mod default;
 pub mod mission_management;
 pub mod brawlers;
@@ -5,4 +6,11 @@ pub mod authentication;
 pub mod mission_operation;
 pub mod mission_viewing;
 pub mod crew_operation;
-pub mod chat;
\ No newline at end of file
+pub mod chat;
+pub mod seasons;
+pub mod ratings;
+pub mod rewards;
+pub mod notifications;
+pub mod mission_series;
+pub mod mission_templates;
+pub mod mission_invitations;
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::use_cases::notifications::NotificationUseCase,
+    domain::repositories::notifications::NotificationRepository,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::notifications::NotificationPostgres,
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let notification_repository = NotificationPostgres::new(db_pool);
+    let use_case = NotificationUseCase::new(Arc::new(notification_repository));
+
+    Router::new()
+        .route("/me", get(my_notifications))
+        .route("/read-all", patch(mark_all_read))
+        .route("/{notification_id}/read", patch(mark_read))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationQuery {
+    pub unread_only: Option<bool>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+pub async fn my_notifications<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Query(query): Query<NotificationQuery>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case
+        .get_mine(
+            brawler_id,
+            query.unread_only.unwrap_or(false),
+            query.page,
+            query.limit,
+        )
+        .await
+    {
+        Ok(notifications) => (StatusCode::OK, Json(notifications)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_read<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(notification_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case
+        .mark_read(brawler_id, Some(notification_id))
+        .await
+    {
+        Ok(updated) => (StatusCode::OK, Json(serde_json::json!({ "updated": updated }))).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_all_read<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case.mark_read(brawler_id, None).await {
+        Ok(updated) => (StatusCode::OK, Json(serde_json::json!({ "updated": updated }))).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
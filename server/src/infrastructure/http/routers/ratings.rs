@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::ratings::RatingUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, mission_viewing::MissionViewingRepository,
+            ratings::RatingRepository,
+        },
+        value_objects::rating_model::{RateBrawlerModel, VoteMvpModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, mission_viewing::MissionViewingPostgres,
+                ratings::RatingPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let rating_repository = RatingPostgres::new(Arc::clone(&db_pool));
+    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+
+    let use_case = RatingUseCase::new(
+        Arc::new(rating_repository),
+        Arc::new(mission_viewing_repository),
+        Arc::new(brawler_repository),
+        config,
+    );
+
+    let protected_router = Router::new()
+        .route("/missions/{mission_id}", get(mission_feedback).post(rate))
+        .route("/missions/{mission_id}/mvp", post(vote_mvp))
+        .route_layer(middleware::from_fn(authorization));
+
+    Router::new()
+        .merge(protected_router)
+        .route("/brawlers/{brawler_id}", get(summary))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn rate<T1, T2, T3>(
+    State(rating_use_case): State<Arc<RatingUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(rate_model): Json<RateBrawlerModel>,
+) -> impl IntoResponse
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    match rating_use_case.rate(mission_id, brawler_id, rate_model).await {
+        Ok(rating_id) => (StatusCode::CREATED, Json(rating_id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn vote_mvp<T1, T2, T3>(
+    State(rating_use_case): State<Arc<RatingUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(vote_model): Json<VoteMvpModel>,
+) -> impl IntoResponse
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    match rating_use_case.vote_mvp(mission_id, brawler_id, vote_model).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mission_feedback<T1, T2, T3>(
+    State(rating_use_case): State<Arc<RatingUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    match rating_use_case
+        .get_mission_feedback(mission_id, brawler_id)
+        .await
+    {
+        Ok(feedback) => (StatusCode::OK, Json(feedback)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn summary<T1, T2, T3>(
+    State(rating_use_case): State<Arc<RatingUseCase<T1, T2, T3>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: RatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: BrawlerRepository + Send + Sync,
+{
+    match rating_use_case.get_summary(brawler_id).await {
+        Ok(summary) => (StatusCode::OK, Json(summary)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::use_cases::rewards::RewardUseCase,
+    domain::{
+        repositories::rewards::RewardRepository, value_objects::reward_kinds::RewardKinds,
+    },
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::rewards::RewardPostgres},
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let reward_repository = RewardPostgres::new(db_pool);
+    let use_case = RewardUseCase::new(Arc::new(reward_repository));
+
+    let protected_router = Router::new()
+        .route("/me", get(my_rewards))
+        .route("/{reward_id}/redeem", post(redeem))
+        .route_layer(middleware::from_fn(authorization));
+
+    Router::new()
+        .merge(protected_router)
+        .route("/", get(catalog))
+        .with_state(Arc::new(use_case))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RewardCatalogQuery {
+    pub kind: Option<RewardKinds>,
+}
+
+pub async fn catalog<T>(
+    State(reward_use_case): State<Arc<RewardUseCase<T>>>,
+    Query(query): Query<RewardCatalogQuery>,
+) -> impl IntoResponse
+where
+    T: RewardRepository + Send + Sync,
+{
+    match reward_use_case.get_catalog(query.kind).await {
+        Ok(rewards) => (StatusCode::OK, Json(rewards)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn my_rewards<T>(
+    State(reward_use_case): State<Arc<RewardUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: RewardRepository + Send + Sync,
+{
+    match reward_use_case.get_owned(brawler_id).await {
+        Ok(rewards) => (StatusCode::OK, Json(rewards)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn redeem<T>(
+    State(reward_use_case): State<Arc<RewardUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(reward_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: RewardRepository + Send + Sync,
+{
+    match reward_use_case.redeem(brawler_id, reward_id).await {
+        Ok(receipt) => (StatusCode::CREATED, Json(receipt)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
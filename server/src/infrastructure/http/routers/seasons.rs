@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::seasons::SeasonUseCase,
+    config::config_model::DotEnvyConfig,
+    domain::{
+        repositories::seasons::SeasonRepository,
+        value_objects::season_model::SeasonStandingFilter,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::seasons::SeasonPostgres,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) -> Router {
+    let season_repository = SeasonPostgres::new(db_pool);
+    let use_case = SeasonUseCase::new(Arc::new(season_repository), config);
+
+    Router::new()
+        .route("/", get(get_all))
+        .route("/current/leaderboard", get(current_leaderboard))
+        .route("/{season_id}/standings", get(final_standings))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn get_all<T>(State(season_use_case): State<Arc<SeasonUseCase<T>>>) -> impl IntoResponse
+where
+    T: SeasonRepository + Send + Sync,
+{
+    match season_use_case.get_all().await {
+        Ok(seasons) => (StatusCode::OK, Json(seasons)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn current_leaderboard<T>(
+    State(season_use_case): State<Arc<SeasonUseCase<T>>>,
+    Query(filter): Query<SeasonStandingFilter>,
+) -> impl IntoResponse
+where
+    T: SeasonRepository + Send + Sync,
+{
+    match season_use_case.get_current_leaderboard(filter.limit).await {
+        Ok(leaderboard) => (StatusCode::OK, Json(leaderboard)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn final_standings<T>(
+    State(season_use_case): State<Arc<SeasonUseCase<T>>>,
+    Path(season_id): Path<i32>,
+    Query(filter): Query<SeasonStandingFilter>,
+) -> impl IntoResponse
+where
+    T: SeasonRepository + Send + Sync,
+{
+    match season_use_case
+        .get_final_standings(season_id, filter.limit)
+        .await
+    {
+        Ok(leaderboard) => (StatusCode::OK, Json(leaderboard)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
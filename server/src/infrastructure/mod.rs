@@ -3,3 +3,5 @@ pub mod database;
 pub mod http;
 pub mod jwt;
 pub mod cloudinary;
+pub mod scheduler;
+pub mod cli;
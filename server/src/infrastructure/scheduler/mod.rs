@@ -0,0 +1,121 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::{
+        mission_management::MissionManagementUseCase,
+        mission_operation::MissionOperationUseCase, mission_series::MissionSeriesUseCase,
+        ratings::RatingUseCase,
+        seasons::SeasonUseCase,
+    },
+    config::config_model::DotEnvyConfig,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementPostgres, brawlers::BrawlerPostgres,
+            crew_operation::CrewOperationPostgres, mission_management::MissionManagementPostgres,
+            mission_operation::MissionOperationPostgres, mission_series::MissionSeriesPostgres,
+            mission_viewing::MissionViewingPostgres,
+            notifications::NotificationPostgres,
+            ratings::RatingPostgres, seasons::SeasonPostgres,
+        },
+    },
+};
+
+pub fn spawn_season_rollover(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) {
+    let interval_secs = config.season.rollover_interval_secs;
+    let season_use_case = SeasonUseCase::new(Arc::new(SeasonPostgres::new(db_pool)), config);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(e) = season_use_case.rollover().await {
+                error!("Season rollover failed: {}", e);
+            }
+        }
+    });
+}
+
+pub fn spawn_mvp_settlement(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) {
+    let interval_secs = config.rating.settle_interval_secs;
+    let rating_use_case = RatingUseCase::new(
+        Arc::new(RatingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(db_pool)),
+        config,
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(e) = rating_use_case.settle_mvps().await {
+                error!("MVP settlement failed: {}", e);
+            }
+        }
+    });
+}
+
+pub fn spawn_mission_archival(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) {
+    let interval_secs = config.archive.interval_secs;
+    let mission_management_use_case = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(db_pool)),
+        config,
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            match mission_management_use_case.archive_finished().await {
+                Ok(0) => {}
+                Ok(archived) => info!("Archived {} finished missions", archived),
+                Err(e) => error!("Mission archival failed: {}", e),
+            }
+        }
+    });
+}
+
+pub fn spawn_due_date_enforcement(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) {
+    let interval_secs = config.due_date.check_interval_secs;
+    let mission_operation_use_case = MissionOperationUseCase::new(
+        Arc::new(MissionOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(NotificationPostgres::new(db_pool)),
+        config,
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(e) = mission_operation_use_case.enforce_due_dates().await {
+                error!("Due-date enforcement failed: {}", e);
+            }
+        }
+    });
+}
+
+pub fn spawn_mission_series(db_pool: Arc<PgPoolSquad>, config: Arc<DotEnvyConfig>) {
+    let interval_secs = config.mission_series.interval_secs;
+    let mission_series_use_case =
+        MissionSeriesUseCase::new(Arc::new(MissionSeriesPostgres::new(db_pool)), config);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(e) = mission_series_use_case.create_due_occurrences().await {
+                error!("Mission series occurrences failed: {}", e);
+            }
+        }
+    });
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use server::{
     config::config_loader,
-    infrastructure::{database::postgresql_connection, http::http_serv::start},
+    infrastructure::{cli, database::postgresql_connection, http::http_serv::start},
 };
 use tracing::{error, info};
 
@@ -32,7 +32,28 @@ async fn main() {
     };
     info!("Connected DB");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
+    let dotenvy_env = Arc::new(dotenvy_env);
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some(cli::RECOMPUTE_POINTS) {
+        if let Err(e) =
+            cli::recompute_points(Arc::new(postgres_pool), dotenvy_env, &args[1..]).await
+        {
+            error!("Failed to recompute points: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().map(String::as_str) == Some(cli::SEED_TEMPLATES) {
+        if let Err(e) =
+            cli::seed_templates(Arc::new(postgres_pool), dotenvy_env, &args[1..]).await
+        {
+            error!("Failed to seed mission templates: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    start(dotenvy_env, Arc::new(postgres_pool))
         .await
         .expect("Failed to start server");
 }